@@ -0,0 +1,128 @@
+//! Compares the `f32` and the `f64` builds:
+//! `cargo bench -- --save-baseline f32`, then `cargo bench --features f64 -- --baseline f32`.
+//! `drift` also prints the moles lost or gained by each build.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use paradise_atmos_rs::gas_mixture::MIXTURES;
+
+const MIXTURES_COUNT: usize = 10_000;
+
+const DRIFT_MIXTURES_COUNT: usize = 100;
+const DRIFT_ROUNDS: usize = 1000;
+/// Id of the mixture that takes the gas removed by `remove_ratio` in `drift`.
+const DRIFT_REMOVED_ID: usize = DRIFT_MIXTURES_COUNT;
+
+/// Standard air on odd ids and a hot plasma mix on even ones, so every pair has something to share and burn.
+unsafe fn fill() {
+    for id in 0..MIXTURES_COUNT {
+        MIXTURES.register(id);
+
+        if id % 2 == 0 {
+            MIXTURES.set_oxygen(id, 40.0);
+            MIXTURES.set_toxins(id, 60.0);
+            MIXTURES.set_temperature(id, 1000.0);
+        } else {
+            MIXTURES.set_oxygen(id, 21.8366);
+            MIXTURES.set_nitrogen(id, 82.1472);
+            MIXTURES.set_temperature(id, 293.15);
+        }
+    }
+}
+
+/// Pipenet sized mixtures, so the moles are big enough for `f32` to round off the moved ones.
+unsafe fn fill_drift() {
+    for id in 0..=DRIFT_MIXTURES_COUNT {
+        MIXTURES.register(id);
+        MIXTURES.set_volume(id, 1_000_000.0);
+    }
+
+    for id in 0..DRIFT_MIXTURES_COUNT {
+        let scale = if id % 2 == 0 { 800.0 } else { 400.0 };
+
+        MIXTURES.set_oxygen(id, 21.8366 * scale);
+        MIXTURES.set_nitrogen(id, 82.1472 * scale);
+        MIXTURES.set_temperature(id, 293.15);
+    }
+}
+
+/// Shares every pair, then moves a quarter of every mixture to the next one with `remove_ratio` and `merge`.
+unsafe fn drift_round() {
+    for id in 0..DRIFT_MIXTURES_COUNT {
+        MIXTURES.archive(id);
+    }
+    for id in 0..DRIFT_MIXTURES_COUNT - 1 {
+        black_box(MIXTURES.share(id, id + 1, 4.0));
+    }
+    for id in 0..DRIFT_MIXTURES_COUNT {
+        MIXTURES.remove_ratio(id, DRIFT_REMOVED_ID, 0.25);
+        MIXTURES.merge((id + 1) % DRIFT_MIXTURES_COUNT, DRIFT_REMOVED_ID);
+    }
+}
+
+unsafe fn drift_total_moles() -> f64 {
+    (0..DRIFT_MIXTURES_COUNT)
+        .map(|id| f64::from(MIXTURES.total_moles(id)))
+        .sum()
+}
+
+fn drift(c: &mut Criterion) {
+    unsafe {
+        fill_drift();
+        let before = drift_total_moles();
+        for _ in 0..DRIFT_ROUNDS {
+            drift_round();
+        }
+        let after = drift_total_moles();
+
+        println!(
+            "drift: {before} -> {after} moles after {DRIFT_ROUNDS} rounds, {:+e} relative",
+            (after - before) / before
+        );
+    }
+
+    c.bench_function("drift", |b| {
+        b.iter_batched(
+            || unsafe { fill_drift() },
+            |()| unsafe {
+                for _ in 0..DRIFT_ROUNDS {
+                    drift_round();
+                }
+            },
+            BatchSize::PerIteration,
+        )
+    });
+}
+
+fn share(c: &mut Criterion) {
+    c.bench_function("share", |b| {
+        b.iter_batched(
+            || unsafe { fill() },
+            |()| unsafe {
+                for id in 0..MIXTURES_COUNT {
+                    MIXTURES.archive(id);
+                }
+                for id in 0..MIXTURES_COUNT - 1 {
+                    black_box(MIXTURES.share(id, id + 1, 4.0));
+                }
+            },
+            BatchSize::PerIteration,
+        )
+    });
+}
+
+fn react(c: &mut Criterion) {
+    c.bench_function("react", |b| {
+        b.iter_batched(
+            || unsafe { fill() },
+            |()| unsafe {
+                for id in 0..MIXTURES_COUNT {
+                    black_box(MIXTURES.react(id));
+                }
+            },
+            BatchSize::PerIteration,
+        )
+    });
+}
+
+criterion_group!(benches, share, react, drift);
+criterion_main!(benches);
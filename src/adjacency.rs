@@ -0,0 +1,358 @@
+mod equalization;
+
+use std::collections::{HashMap, HashSet};
+
+use byondapi::value::ByondValue;
+use once_cell::unsync::Lazy;
+
+use crate::config::CONFIG;
+use crate::gas_mixture::{Mixture, Overlays};
+use crate::profile;
+use crate::reservoir::Reservoirs;
+use crate::stats::STATS;
+use crate::utils::{to_number, Float};
+
+/// ### Description
+/// Cardinal neighbours of the turfs that are simulated by the Rust turf tick, keyed by the id of the turf's mixture.
+pub static mut ADJACENCY: Lazy<Adjacency> = Lazy::new(Adjacency::default);
+
+/// ### Description
+/// Directions, the same as `NORTH`, `SOUTH`, `EAST` and `WEST` in `DM`.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    North = 1,
+    South = 2,
+    East = 4,
+    West = 8,
+}
+
+impl Direction {
+    /// ### Description
+    /// Order of the neighbours in [`TurfNode::neighbours`] and in the lists passed from `DM`.
+    pub const CARDINALS: [Self; 4] = [Self::North, Self::South, Self::East, Self::West];
+
+    #[must_use]
+    #[inline(always)]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::South => Self::North,
+            Self::East => Self::West,
+            Self::West => Self::East,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbour {
+    /// Blocked by a wall, a closed door and so on.
+    #[default]
+    None,
+    /// Simulated turf, the id of its mixture.
+    Mixture(usize),
+    /// Unsimulated turf, the id of a reservoir in [`crate::reservoir::RESERVOIRS`].
+    Reservoir(usize),
+}
+
+impl Neighbour {
+    /// ### Description
+    /// Reads a neighbour from `DM`: `null` is blocked, a number is a reservoir id, anything else is a mixture.
+    #[must_use]
+    pub fn new(value: ByondValue) -> Self {
+        if value.is_null() {
+            Self::None
+        } else if let Ok(reservoir_id) = value.get_number() {
+            Self::Reservoir(reservoir_id as usize)
+        } else {
+            Self::Mixture(crate::id!(value))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TurfNode {
+    /// Turfs are never deleted by `BYOND`, so it's safe to keep the reference without a refcount.
+    pub turf: ByondValue,
+    /// Neighbours in the order of [`Direction::CARDINALS`].
+    pub neighbours: [Neighbour; 4],
+}
+
+impl TurfNode {
+    #[must_use]
+    #[inline(always)]
+    pub fn atmos_adjacent_turfs(&self) -> Float {
+        self.neighbours
+            .iter()
+            .filter(|neighbour| **neighbour != Neighbour::None)
+            .count() as Float
+    }
+}
+
+/// ### Description
+/// The biggest pressure difference of a turf in the tick, `DM` throws movables of the turf towards `direction`.
+#[derive(Debug, Clone, Copy)]
+pub struct PressureMovement {
+    pub turf: ByondValue,
+    pub direction: Direction,
+    pub force: Float,
+}
+
+impl PressureMovement {
+    pub fn to_list(self) -> eyre::Result<ByondValue> {
+        Ok([
+            self.turf,
+            ByondValue::from(self.direction as u8 as f32),
+            ByondValue::from(to_number(self.force)),
+        ]
+        .as_slice()
+        .try_into()?)
+    }
+}
+
+/// ### Description
+/// Outcome of a turf tick, applied by `DM`.
+#[derive(Debug, Default)]
+pub struct ProcessResult {
+    pub pressure_movements: Vec<PressureMovement>,
+    /// Turfs where `water_vapour` has condensed this tick.
+    pub wet_turfs: Vec<ByondValue>,
+    /// Turfs whose overlays have changed this tick, with the new overlays.
+    /// Only the processed turfs and their neighbours are checked, a mixture changed by `DM` on an inactive turf
+    /// keeps its overlays until the turf is activated or `get_overlays` is called on it.
+    pub overlay_changes: Vec<(ByondValue, Overlays)>,
+}
+
+impl ProcessResult {
+    const PRESSURE_MOVEMENTS: &'static str = "pressure_movements";
+    const WET_TURFS: &'static str = "wet_turfs";
+    const OVERLAY_CHANGES: &'static str = "overlay_changes";
+
+    /// ### Description
+    /// Converts the result to `list("pressure_movements" = list(list(turf, direction, force), ...), "wet_turfs" = list(turf, ...),
+    /// "overlay_changes" = list(list(turf, list(gas_name, ...)), ...))`.
+    pub fn to_list(self) -> eyre::Result<ByondValue> {
+        let mut pressure_movements = ByondValue::new_list()?;
+        for pressure_movement in self.pressure_movements {
+            pressure_movements.push_list(pressure_movement.to_list()?)?;
+        }
+
+        let wet_turfs: ByondValue = self.wet_turfs.as_slice().try_into()?;
+
+        let mut overlay_changes = ByondValue::new_list()?;
+        for (turf, overlays) in self.overlay_changes {
+            let overlay_change: ByondValue = [turf, overlays.to_list()?].as_slice().try_into()?;
+            overlay_changes.push_list(overlay_change)?;
+        }
+
+        let mut list = ByondValue::new_list()?;
+        list.write_list_index(Self::PRESSURE_MOVEMENTS, pressure_movements)?;
+        list.write_list_index(Self::WET_TURFS, wet_turfs)?;
+        list.write_list_index(Self::OVERLAY_CHANGES, overlay_changes)?;
+
+        Ok(list)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Adjacency {
+    nodes: HashMap<usize, TurfNode>,
+}
+
+impl Adjacency {
+    #[inline(always)]
+    pub fn set(&mut self, id: usize, node: TurfNode) {
+        self.nodes.insert(id, node);
+    }
+
+    #[inline(always)]
+    pub fn remove(&mut self, id: usize) {
+        self.nodes.remove(&id);
+    }
+
+    #[must_use]
+    #[inline(always)]
+    pub fn get(&self, id: usize) -> Option<&TurfNode> {
+        self.nodes.get(&id)
+    }
+
+    /// ### Description
+    /// Number of the turfs simulated by the turf tick.
+    #[must_use]
+    #[inline(always)]
+    pub fn turfs_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// ### Description
+    /// Shares the air of every `active` turf with its neighbours, the same as `process_cell` in `DM`.
+    /// Each pair of turfs shares only once per tick.
+    /// If [`crate::config::Config::equalization`] is enabled, regions with a big pressure gradient are equalized first.
+    /// After the sharing `water_vapour` condenses on the active turfs that are below the dew point,
+    /// and the overlays of the processed turfs and their neighbours are updated.
+    /// The mutators don't update overlays, so `DM` has to activate a turf whose mixture it has changed or call `get_overlays` on it.
+    /// ### Returns
+    /// The biggest pressure difference of every turf that has one, pointing from the higher pressure to the lower one,
+    /// the turfs that got wet and the turfs whose overlays have changed.
+    #[must_use]
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    pub unsafe fn process(
+        &self,
+        mixtures: &mut Mixture,
+        reservoirs: &Reservoirs,
+        active: &[usize],
+    ) -> ProcessResult {
+        profile!("process_turfs");
+
+        STATS.current.active_turfs += active.len();
+
+        let mut pressure_differences = HashMap::new();
+
+        if CONFIG.equalization {
+            self.equalize(mixtures, reservoirs, active, &mut pressure_differences);
+        }
+
+        // Everything has to be archived before any sharing, otherwise a turf would archive the air it got this tick.
+        let mut to_archive = Vec::with_capacity(active.len() * (Direction::CARDINALS.len() + 1));
+        for &id in active {
+            to_archive.push(id);
+
+            let Some(node) = self.get(id) else {
+                continue;
+            };
+
+            for neighbour in node.neighbours {
+                if let Neighbour::Mixture(neighbour_id) = neighbour {
+                    to_archive.push(neighbour_id);
+                }
+            }
+        }
+        mixtures.archive_many(&mut to_archive);
+
+        let mut processed = HashSet::with_capacity(active.len());
+
+        for &id in active {
+            let Some(node) = self.get(id) else {
+                continue;
+            };
+
+            processed.insert(id);
+            let atmos_adjacent_turfs = node.atmos_adjacent_turfs();
+
+            for (direction, neighbour) in Direction::CARDINALS.into_iter().zip(node.neighbours) {
+                match neighbour {
+                    Neighbour::None => {}
+                    Neighbour::Mixture(neighbour_id) => {
+                        if processed.contains(&neighbour_id) {
+                            continue;
+                        }
+
+                        let difference = mixtures.share(id, neighbour_id, atmos_adjacent_turfs);
+                        if difference > 0.0 {
+                            consider_pressure_difference(
+                                &mut pressure_differences,
+                                id,
+                                direction,
+                                difference,
+                            );
+                        } else if difference < 0.0 {
+                            consider_pressure_difference(
+                                &mut pressure_differences,
+                                neighbour_id,
+                                direction.opposite(),
+                                -difference,
+                            );
+                        }
+                    }
+                    Neighbour::Reservoir(reservoir_id) => {
+                        let Some(reservoir) = reservoirs.get(reservoir_id) else {
+                            continue;
+                        };
+
+                        let difference = mixtures.mimic(
+                            id,
+                            reservoir.model,
+                            reservoir.thermal_conductivity,
+                            reservoir.heat_capacity,
+                            atmos_adjacent_turfs,
+                        );
+                        if difference > 0.0 {
+                            consider_pressure_difference(
+                                &mut pressure_differences,
+                                id,
+                                direction,
+                                difference,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        let wet_turfs = active
+            .iter()
+            .filter_map(|&id| {
+                let node = self.get(id)?;
+                (mixtures.condense(id) > 0.0).then_some(node.turf)
+            })
+            .collect();
+
+        let mut overlay_changes = Vec::new();
+        let mut updated = HashSet::with_capacity(processed.len());
+        for id in processed {
+            let Some(node) = self.get(id) else {
+                continue;
+            };
+
+            let neighbours = node
+                .neighbours
+                .into_iter()
+                .filter_map(|neighbour| match neighbour {
+                    Neighbour::Mixture(neighbour_id) => Some(neighbour_id),
+                    _ => None,
+                });
+            for id in std::iter::once(id).chain(neighbours) {
+                if !updated.insert(id) || !mixtures.update_overlays(id) {
+                    continue;
+                }
+
+                if let Some(node) = self.get(id) {
+                    overlay_changes.push((node.turf, mixtures.get_overlays(id)));
+                }
+            }
+        }
+
+        let pressure_movements = pressure_differences
+            .into_iter()
+            .filter_map(|(id, (direction, force))| {
+                Some(PressureMovement {
+                    turf: self.get(id)?.turf,
+                    direction,
+                    force,
+                })
+            })
+            .collect();
+
+        ProcessResult {
+            pressure_movements,
+            wet_turfs,
+            overlay_changes,
+        }
+    }
+}
+
+/// ### Description
+/// Keeps the biggest pressure difference of the `id` turf, the same as `consider_pressure_difference` in `DM`.
+#[inline(always)]
+fn consider_pressure_difference(
+    pressure_differences: &mut HashMap<usize, (Direction, Float)>,
+    id: usize,
+    direction: Direction,
+    difference: Float,
+) {
+    let pressure_difference = pressure_differences.entry(id).or_insert((direction, 0.0));
+    if difference > pressure_difference.1 {
+        *pressure_difference = (direction, difference);
+    }
+}
@@ -0,0 +1,319 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::{consider_pressure_difference, Adjacency, Direction, Neighbour};
+use crate::config::CONFIG;
+use crate::constants::*;
+use crate::gas_mixture::{Gas, Mixture};
+use crate::profile;
+use crate::reservoir::{Reservoir, Reservoirs};
+use crate::utils::Float;
+
+/// ### Description
+/// Where the gas of a turf of a region flows to.
+#[derive(Debug, Clone, Copy)]
+enum Outlet {
+    /// The turf the flow tree starts from, nothing flows out of it.
+    Root,
+    /// Index of a turf in the region and the direction to it.
+    Turf(usize, Direction),
+    /// A reservoir in the direction.
+    Reservoir(Direction),
+}
+
+impl Adjacency {
+    /// ### Description
+    /// Monstermos-like equalization.
+    /// Every `active` turf with a big pressure gradient and the turfs connected to it are equalized in one pass,
+    /// instead of waiting for dozens of ticks of [`Mixture::share`].
+    /// If the region touches a reservoir (e.g. a hull breach into space), the whole region gets the reservoir's air.
+    /// ### Pressure movement
+    /// Mole flows are calculated over a spanning tree of the region and are written to `pressure_differences` in kPa.
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    pub unsafe fn equalize(
+        &self,
+        mixtures: &mut Mixture,
+        reservoirs: &Reservoirs,
+        active: &[usize],
+        pressure_differences: &mut HashMap<usize, (Direction, Float)>,
+    ) {
+        profile!("equalize");
+
+        let mut visited = HashSet::new();
+
+        for &id in active {
+            if visited.contains(&id) || !self.has_big_pressure_gradient(mixtures, reservoirs, id) {
+                continue;
+            }
+
+            let region = self.find_region(id, &mut visited);
+            self.equalize_region(mixtures, reservoirs, &region, pressure_differences);
+        }
+    }
+
+    #[must_use]
+    #[inline(always)]
+    unsafe fn has_big_pressure_gradient(
+        &self,
+        mixtures: &Mixture,
+        reservoirs: &Reservoirs,
+        id: usize,
+    ) -> bool {
+        let Some(node) = self.get(id) else {
+            return false;
+        };
+
+        let pressure = mixtures.return_pressure(id);
+
+        node.neighbours.into_iter().any(|neighbour| {
+            let neighbour_pressure = match neighbour {
+                Neighbour::None => return false,
+                Neighbour::Mixture(neighbour_id) => mixtures.return_pressure(neighbour_id),
+                Neighbour::Reservoir(reservoir_id) => match reservoirs.get(reservoir_id) {
+                    Some(reservoir) => Self::reservoir_pressure(mixtures, reservoir, id),
+                    None => return false,
+                },
+            };
+
+            (pressure - neighbour_pressure).abs() > CONFIG.equalization_minimum_pressure_difference
+        })
+    }
+
+    /// ### Description
+    /// Pressure of a reservoir as if its air was in the volume of the `id` mixture.
+    #[must_use]
+    #[inline(always)]
+    unsafe fn reservoir_pressure(mixtures: &Mixture, reservoir: &Reservoir, id: usize) -> Float {
+        let volume = mixtures.get_volume(id);
+        if volume <= Default::default() {
+            return Default::default();
+        }
+
+        reservoir.model.total_moles() * R_IDEAL_GAS_EQUATION * reservoir.model.temperature / volume
+    }
+
+    /// ### Description
+    /// Breadth-first search over the simulated turfs connected to `id`.
+    /// The region is limited by [`crate::config::Config::equalization_max_region_size`].
+    #[must_use]
+    unsafe fn find_region(&self, id: usize, visited: &mut HashSet<usize>) -> Vec<usize> {
+        let mut region = Vec::new();
+        let mut queue = VecDeque::from([id]);
+        visited.insert(id);
+
+        while let Some(id) = queue.pop_front() {
+            region.push(id);
+            if region.len() + queue.len() >= CONFIG.equalization_max_region_size {
+                region.extend(queue);
+
+                break;
+            }
+
+            let Some(node) = self.get(id) else {
+                continue;
+            };
+
+            for neighbour in node.neighbours {
+                if let Neighbour::Mixture(neighbour_id) = neighbour {
+                    if self.get(neighbour_id).is_some() && visited.insert(neighbour_id) {
+                        queue.push_back(neighbour_id);
+                    }
+                }
+            }
+        }
+
+        region
+    }
+
+    unsafe fn equalize_region(
+        &self,
+        mixtures: &mut Mixture,
+        reservoirs: &Reservoirs,
+        region: &[usize],
+        pressure_differences: &mut HashMap<usize, (Direction, Float)>,
+    ) {
+        let indices = region
+            .iter()
+            .enumerate()
+            .map(|(index, &id)| (id, index))
+            .collect::<HashMap<_, _>>();
+
+        let mut total_gases = [0.0; Gas::ALL.len()];
+        let mut total_volume = 0.0;
+        let mut total_heat_capacity = 0.0;
+        let mut total_thermal_energy = 0.0;
+        let mut outlets = vec![Outlet::Root; region.len()];
+        let mut sink = None;
+
+        for (index, &id) in region.iter().enumerate() {
+            for (total, gas) in total_gases.iter_mut().zip(Gas::ALL) {
+                *total += mixtures.get_gas(id, gas);
+            }
+            total_volume += mixtures.get_volume(id);
+            total_heat_capacity += mixtures.heat_capacity(id);
+            total_thermal_energy += mixtures.thermal_energy(id);
+
+            let Some(node) = self.get(id) else {
+                continue;
+            };
+
+            for (direction, neighbour) in Direction::CARDINALS.into_iter().zip(node.neighbours) {
+                if let Neighbour::Reservoir(reservoir_id) = neighbour {
+                    if let Some(reservoir) = reservoirs.get(reservoir_id) {
+                        outlets[index] = Outlet::Reservoir(direction);
+                        sink.get_or_insert(*reservoir);
+
+                        break;
+                    }
+                }
+            }
+        }
+
+        if total_volume <= Default::default() {
+            return;
+        }
+
+        // Target amount of gases of each turf, per unit of volume for the closed region.
+        let (target_gases, target_temperature, is_per_volume) = match sink {
+            Some(reservoir) => (
+                Gas::ALL.map(|gas| reservoir.model.get_gas(gas)),
+                Some(reservoir.model.temperature),
+                false,
+            ),
+            None => {
+                let temperature = (total_heat_capacity > MINIMUM_HEAT_CAPACITY)
+                    .then(|| total_thermal_energy / total_heat_capacity);
+
+                (
+                    total_gases.map(|total| total / total_volume),
+                    temperature,
+                    true,
+                )
+            }
+        };
+
+        let target_total_moles = target_gases.iter().sum::<Float>();
+        let mut excess_moles = region
+            .iter()
+            .map(|&id| {
+                let scale = if is_per_volume {
+                    mixtures.get_volume(id)
+                } else {
+                    1.0
+                };
+
+                mixtures.total_moles(id) - target_total_moles * scale
+            })
+            .collect::<Vec<_>>();
+        let moved_moles = excess_moles
+            .iter()
+            .map(|moles| moles.abs())
+            .collect::<Vec<_>>();
+
+        // Spanning tree from the turfs next to the reservoir or from the first turf of the region.
+        let mut order = Vec::with_capacity(region.len());
+        let mut queue = (0..region.len())
+            .filter(|&index| matches!(outlets[index], Outlet::Reservoir(_)))
+            .collect::<VecDeque<_>>();
+        if queue.is_empty() {
+            queue.push_back(0);
+        }
+
+        let mut visited = vec![false; region.len()];
+        for &index in &queue {
+            visited[index] = true;
+        }
+
+        while let Some(index) = queue.pop_front() {
+            order.push(index);
+
+            let Some(node) = self.get(region[index]) else {
+                continue;
+            };
+
+            for (direction, neighbour) in Direction::CARDINALS.into_iter().zip(node.neighbours) {
+                let Neighbour::Mixture(neighbour_id) = neighbour else {
+                    continue;
+                };
+                let Some(&neighbour_index) = indices.get(&neighbour_id) else {
+                    continue;
+                };
+
+                if !visited[neighbour_index] {
+                    visited[neighbour_index] = true;
+                    outlets[neighbour_index] = Outlet::Turf(index, direction.opposite());
+                    queue.push_back(neighbour_index);
+                }
+            }
+        }
+
+        // Leaves push their excess towards the outlets, so each edge carries the flow of its whole subtree.
+        for &index in order.iter().rev() {
+            let flow = excess_moles[index];
+            let id = region[index];
+
+            match outlets[index] {
+                Outlet::Root => {}
+                Outlet::Turf(outlet_index, direction) => {
+                    excess_moles[outlet_index] += flow;
+
+                    if flow > 0.0 {
+                        consider_pressure_difference(
+                            pressure_differences,
+                            id,
+                            direction,
+                            Self::moles_to_pressure(mixtures, id, flow),
+                        );
+                    } else if flow < 0.0 {
+                        let outlet_id = region[outlet_index];
+                        consider_pressure_difference(
+                            pressure_differences,
+                            outlet_id,
+                            direction.opposite(),
+                            Self::moles_to_pressure(mixtures, outlet_id, -flow),
+                        );
+                    }
+                }
+                Outlet::Reservoir(direction) => {
+                    if flow > 0.0 {
+                        consider_pressure_difference(
+                            pressure_differences,
+                            id,
+                            direction,
+                            Self::moles_to_pressure(mixtures, id, flow),
+                        );
+                    }
+                }
+            }
+        }
+
+        for (index, &id) in region.iter().enumerate() {
+            let scale = if is_per_volume {
+                mixtures.get_volume(id)
+            } else {
+                1.0
+            };
+
+            for (target, gas) in target_gases.into_iter().zip(Gas::ALL) {
+                mixtures.set_gas(id, gas, target * scale);
+            }
+
+            if let Some(temperature) = target_temperature {
+                mixtures.set_temperature(id, temperature);
+            }
+
+            mixtures.set_last_share(id, moved_moles[index]);
+        }
+    }
+
+    #[must_use]
+    #[inline(always)]
+    unsafe fn moles_to_pressure(mixtures: &Mixture, id: usize, moles: Float) -> Float {
+        let volume = mixtures.get_volume(id);
+        if volume <= Default::default() {
+            return Default::default();
+        }
+
+        moles * R_IDEAL_GAS_EQUATION * mixtures.get_temperature(id) / volume
+    }
+}
@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use byondapi::value::ByondValue;
+use once_cell::unsync::Lazy;
+
+use crate::constants::*;
+use crate::gas_mixture::{Gas, Mixture};
+use crate::profile;
+use crate::utils::{from_number, Float};
+
+/// ### Description
+/// Alarm profiles registered from `DM`, keyed by the id of the datum that owns them (usually an air alarm).
+pub static mut ALARM_PROFILES: Lazy<HashMap<usize, AlarmProfile>> = Lazy::new(HashMap::new);
+
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AlarmLevel {
+    #[default]
+    None = 0,
+    Warning = 1,
+    Danger = 2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlarmMetric {
+    Pressure,
+    Temperature,
+    Gas(Gas),
+}
+
+impl AlarmMetric {
+    const PRESSURE: &'static str = "pressure";
+    const TEMPERATURE: &'static str = "temperature";
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Pressure => Self::PRESSURE,
+            Self::Temperature => Self::TEMPERATURE,
+            Self::Gas(gas) => gas.name(),
+        }
+    }
+
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            Self::PRESSURE => Some(Self::Pressure),
+            Self::TEMPERATURE => Some(Self::Temperature),
+            _ => Gas::from_name(name).map(Self::Gas),
+        }
+    }
+}
+
+/// ### Description
+/// Limits of a single metric, the same as `TLV` in `DM`.
+/// Negative limit disables the check.
+#[derive(Debug, Clone, Copy)]
+pub struct Thresholds {
+    pub danger_min: Float,
+    pub warning_min: Float,
+    pub warning_max: Float,
+    pub danger_max: Float,
+}
+
+impl Thresholds {
+    const DISABLED: Self = Self {
+        danger_min: -1.0,
+        warning_min: -1.0,
+        warning_max: -1.0,
+        danger_max: -1.0,
+    };
+
+    /// ### Description
+    /// Reads the thresholds from a `DM` list of `list(danger_min, warning_min, warning_max, danger_max)`.
+    pub fn new(list: ByondValue) -> eyre::Result<Self> {
+        let values = list.get_list_values()?;
+        let [danger_min, warning_min, warning_max, danger_max] = values.as_slice() else {
+            eyre::bail!(
+                "Thresholds must have exactly 4 values, got {}",
+                values.len()
+            );
+        };
+
+        Ok(Self {
+            danger_min: from_number(danger_min.get_number()?),
+            warning_min: from_number(warning_min.get_number()?),
+            warning_max: from_number(warning_max.get_number()?),
+            danger_max: from_number(danger_max.get_number()?),
+        })
+    }
+
+    #[must_use]
+    #[inline(always)]
+    pub fn level(&self, value: Float) -> AlarmLevel {
+        let is_below = |limit: Float| limit >= 0.0 && value < limit;
+        let is_above = |limit: Float| limit >= 0.0 && value > limit;
+
+        if is_below(self.danger_min) || is_above(self.danger_max) {
+            AlarmLevel::Danger
+        } else if is_below(self.warning_min) || is_above(self.warning_max) {
+            AlarmLevel::Warning
+        } else {
+            AlarmLevel::None
+        }
+    }
+}
+
+/// ### Description
+/// Limits of pressure, temperature and partial pressure of each gas.
+#[derive(Debug, Clone, Copy)]
+pub struct AlarmProfile {
+    pub pressure: Thresholds,
+    pub temperature: Thresholds,
+    /// Partial pressure limits in the order of [`Gas::ALL`].
+    pub gases: [Thresholds; Gas::ALL.len()],
+}
+
+impl AlarmProfile {
+    /// ### Description
+    /// Reads the profile from an associative `DM` list of `metric name = list(thresholds)`.
+    /// Metrics that aren't in the list are never checked.
+    pub fn new(list: ByondValue) -> eyre::Result<Self> {
+        let mut profile = Self::default();
+
+        for (name, thresholds) in list.iter()? {
+            let name = name.get_string()?;
+            let Some(metric) = AlarmMetric::from_name(&name) else {
+                eyre::bail!("Unknown alarm metric: {name}");
+            };
+
+            *profile.thresholds_mut(metric) = Thresholds::new(thresholds)?;
+        }
+
+        Ok(profile)
+    }
+
+    #[must_use]
+    #[inline(always)]
+    fn thresholds_mut(&mut self, metric: AlarmMetric) -> &mut Thresholds {
+        match metric {
+            AlarmMetric::Pressure => &mut self.pressure,
+            AlarmMetric::Temperature => &mut self.temperature,
+            AlarmMetric::Gas(gas) => &mut self.gases[gas as usize],
+        }
+    }
+
+    /// ### Description
+    /// Checks every mixture of `ids` and returns the worst level and the metric that tripped it.
+    /// If several metrics are at the worst level, the first one found is returned.
+    #[must_use]
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    pub unsafe fn evaluate(
+        &self,
+        mixtures: &Mixture,
+        ids: impl IntoIterator<Item = usize>,
+    ) -> (AlarmLevel, Option<AlarmMetric>) {
+        profile!("evaluate_alarms");
+
+        let mut worst = (AlarmLevel::None, None);
+
+        for id in ids {
+            let volume = mixtures.get_volume(id);
+            let pressure_per_mole = if volume > Default::default() {
+                R_IDEAL_GAS_EQUATION * mixtures.get_temperature(id) / volume
+            } else {
+                Default::default()
+            };
+
+            let readings = [
+                (AlarmMetric::Pressure, mixtures.return_pressure(id)),
+                (AlarmMetric::Temperature, mixtures.get_temperature(id)),
+            ]
+            .into_iter()
+            .chain(Gas::ALL.into_iter().map(|gas| {
+                (
+                    AlarmMetric::Gas(gas),
+                    mixtures.get_gas(id, gas) * pressure_per_mole,
+                )
+            }));
+
+            for (metric, value) in readings {
+                let level = match metric {
+                    AlarmMetric::Pressure => self.pressure.level(value),
+                    AlarmMetric::Temperature => self.temperature.level(value),
+                    AlarmMetric::Gas(gas) => self.gases[gas as usize].level(value),
+                };
+
+                if level > worst.0 {
+                    worst = (level, Some(metric));
+
+                    if level == AlarmLevel::Danger {
+                        return worst;
+                    }
+                }
+            }
+        }
+
+        worst
+    }
+}
+
+impl Default for AlarmProfile {
+    fn default() -> Self {
+        Self {
+            pressure: Thresholds::DISABLED,
+            temperature: Thresholds::DISABLED,
+            gases: [Thresholds::DISABLED; Gas::ALL.len()],
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use byondapi::value::ByondValue;
+use serde::Deserialize;
+
+use crate::constants::*;
+use crate::utils::{self, Float};
+
+/// ### Description
+/// Per-server settings of the simulation.
+/// Starts from the compile-time constants and can be overridden at round start from a `TOML` file or from `DM`.
+pub static mut CONFIG: Config = Config::DEFAULT;
+
+/// ### Description
+/// Conversion of config entries from and to `DM` numbers.
+trait ConfigValue: Sized {
+    fn from_number(value: f32) -> eyre::Result<Self>;
+    fn to_number(self) -> f32;
+}
+
+impl ConfigValue for Float {
+    #[inline(always)]
+    fn from_number(value: f32) -> eyre::Result<Self> {
+        Ok(utils::from_number(value))
+    }
+
+    #[inline(always)]
+    fn to_number(self) -> f32 {
+        utils::to_number(self)
+    }
+}
+
+impl ConfigValue for bool {
+    #[inline(always)]
+    fn from_number(value: f32) -> eyre::Result<Self> {
+        Ok(value != 0.0)
+    }
+
+    #[inline(always)]
+    fn to_number(self) -> f32 {
+        self as u8 as f32
+    }
+}
+
+impl ConfigValue for usize {
+    #[inline(always)]
+    fn from_number(value: f32) -> eyre::Result<Self> {
+        if !value.is_finite() || value < 0.0 || value.fract() != 0.0 {
+            eyre::bail!("Expected a non-negative integer, got {value}");
+        }
+
+        Ok(value as usize)
+    }
+
+    #[inline(always)]
+    fn to_number(self) -> f32 {
+        self as f32
+    }
+}
+
+macro_rules! config {
+    ($($(#[$meta:meta])* $field:ident: $type:ty = $default:expr;)+) => {
+        #[derive(Debug, Clone, Copy, Deserialize)]
+        #[serde(default, deny_unknown_fields)]
+        pub struct Config {
+            $(
+                $(#[$meta])*
+                pub $field: $type,
+            )+
+        }
+
+        impl Config {
+            pub const DEFAULT: Self = Self {
+                $($field: $default,)+
+            };
+
+            /// ### Description
+            /// Sets an entry by the name of its field.
+            pub fn set(&mut self, name: &str, value: f32) -> eyre::Result<()> {
+                match name {
+                    $(stringify!($field) => self.$field = ConfigValue::from_number(value)?,)+
+                    _ => eyre::bail!("Unknown config entry: {name}"),
+                }
+
+                Ok(())
+            }
+
+            /// ### Description
+            /// Fails on the first entry that isn't a finite number.
+            fn check_finite(&self) -> eyre::Result<()> {
+                $(
+                    if !self.$field.to_number().is_finite() {
+                        eyre::bail!("Config entry {} must be finite", stringify!($field));
+                    }
+                )+
+
+                Ok(())
+            }
+
+            /// ### Description
+            /// Converts the config to an associative `DM` list, keyed by the names of the fields.
+            pub fn to_list(&self) -> eyre::Result<ByondValue> {
+                let mut list = ByondValue::new_list()?;
+                $(list.write_list_index(stringify!($field), self.$field.to_number())?;)+
+
+                Ok(list)
+            }
+        }
+    };
+}
+
+config! {
+    /// Equalize regions with a big pressure gradient in one pass, see [`crate::adjacency::Adjacency::equalize`].
+    equalization: bool = false;
+    equalization_minimum_pressure_difference: Float = EQUALIZATION_MINIMUM_PRESSURE_DIFFERENCE;
+    equalization_max_region_size: usize = EQUALIZATION_MAX_REGION_SIZE;
+
+    toxins_visible_moles: Float = MOLES_TOXINS_VISIBLE;
+    sleeping_agent_visible_moles: Float = MOLES_SLEEPING_AGENT_VISIBLE;
+
+    /// Fix float errors after `share`, `mimic` and `react`, see [`crate::gas_mixture::Mixture::sanitize`].
+    sanitization: bool = false;
+
+    /// Record the execution time of every proc to `proc_time` of the stats, costs two clock reads and a map update per call.
+    proc_timing: bool = false;
+
+    minimum_air_ratio_to_suspend: Float = MINIMUM_AIR_RATIO_TO_SUSPEND;
+    minimum_temperature_to_move: Float = MINIMUM_TEMPERATURE_TO_MOVE;
+    minimum_temperature_delta_to_suspend: Float = MINIMUM_TEMPERATURE_DELTA_TO_SUSPEND;
+    minimum_temperature_delta_to_consider: Float = MINIMUM_TEMPERATURE_DELTA_TO_CONSIDER;
+
+    // Heat transfer coefficients aren't used by the Rust side except of the open one,
+    // but `DM` reads them from here, so they are configured in one place.
+    floor_heat_transfer_coefficient: Float = FLOOR_HEAT_TRANSFER_COEFFICIENT;
+    wall_heat_transfer_coefficient: Float = WALL_HEAT_TRANSFER_COEFFICIENT;
+    open_heat_transfer_coefficient: Float = OPEN_HEAT_TRANSFER_COEFFICIENT;
+    window_heat_transfer_coefficient: Float = WINDOW_HEAT_TRANSFER_COEFFICIENT;
+
+    fire_minimum_temperature_to_exist: Float = FIRE_MINIMUM_TEMPERATURE_TO_EXIST;
+    fire_plasma_energy_released: Float = FIRE_PLASMA_ENERGY_RELEASED;
+
+    oxygen_burn_rate_base: Float = OXYGEN_BURN_RATE_BASE;
+    plasma_burn_rate_delta: Float = PLASMA_BURN_RATE_DELTA;
+    plasma_minimum_burn_temperature: Float = PLASMA_MINIMUM_BURN_TEMPERATURE;
+    plasma_upper_temperature: Float = PLASMA_UPPER_TEMPERATURE;
+    plasma_minimum_oxygen_needed: Float = PLASMA_MINIMUM_OXYGEN_NEEDED;
+    plasma_oxygen_fullburn: Float = PLASMA_OXYGEN_FULLBURN;
+
+    fire_hydrogen_energy_released: Float = FIRE_HYDROGEN_ENERGY_RELEASED;
+    hydrogen_burn_rate_delta: Float = HYDROGEN_BURN_RATE_DELTA;
+    hydrogen_minimum_burn_temperature: Float = HYDROGEN_MINIMUM_BURN_TEMPERATURE;
+    hydrogen_upper_temperature: Float = HYDROGEN_UPPER_TEMPERATURE;
+    hydrogen_oxygen_fullburn: Float = HYDROGEN_OXYGEN_FULLBURN;
+    /// Burnt `hydrogen` turns into `water_vapour`, otherwise it just disappears.
+    hydrogen_fire_produces_water_vapour: bool = true;
+
+    sleeping_agent_decomposition_minimum_temperature: Float = SLEEPING_AGENT_DECOMPOSITION_MINIMUM_TEMPERATURE;
+    sleeping_agent_decomposition_upper_temperature: Float = SLEEPING_AGENT_DECOMPOSITION_UPPER_TEMPERATURE;
+    sleeping_agent_decomposition_rate_delta: Float = SLEEPING_AGENT_DECOMPOSITION_RATE_DELTA;
+    sleeping_agent_decomposition_energy_released: Float = SLEEPING_AGENT_DECOMPOSITION_ENERGY_RELEASED;
+}
+
+impl Config {
+    /// ### Description
+    /// Reads the config from a `TOML` file. Entries that aren't in the file keep their defaults.
+    pub fn from_file(path: &str) -> eyre::Result<Self> {
+        Ok(toml::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// ### Description
+    /// Rejects the entries that would make the reactions divide by zero or heat transfer create energy:
+    /// non-finite values, non-positive rate deltas and fullburn ratios, inverted temperature ranges
+    /// and heat transfer coefficients out of `0..=1`.
+    pub fn validate(&self) -> eyre::Result<()> {
+        self.check_finite()?;
+
+        for (name, value) in [
+            ("plasma_burn_rate_delta", self.plasma_burn_rate_delta),
+            ("plasma_oxygen_fullburn", self.plasma_oxygen_fullburn),
+            ("hydrogen_burn_rate_delta", self.hydrogen_burn_rate_delta),
+            ("hydrogen_oxygen_fullburn", self.hydrogen_oxygen_fullburn),
+            (
+                "sleeping_agent_decomposition_rate_delta",
+                self.sleeping_agent_decomposition_rate_delta,
+            ),
+        ] {
+            if value <= 0.0 {
+                eyre::bail!("Config entry {name} must be positive, got {value}");
+            }
+        }
+
+        for (minimum_name, minimum, upper_name, upper) in [
+            (
+                "plasma_minimum_burn_temperature",
+                self.plasma_minimum_burn_temperature,
+                "plasma_upper_temperature",
+                self.plasma_upper_temperature,
+            ),
+            (
+                "hydrogen_minimum_burn_temperature",
+                self.hydrogen_minimum_burn_temperature,
+                "hydrogen_upper_temperature",
+                self.hydrogen_upper_temperature,
+            ),
+            (
+                "sleeping_agent_decomposition_minimum_temperature",
+                self.sleeping_agent_decomposition_minimum_temperature,
+                "sleeping_agent_decomposition_upper_temperature",
+                self.sleeping_agent_decomposition_upper_temperature,
+            ),
+        ] {
+            if upper <= minimum {
+                eyre::bail!(
+                    "Config entry {upper_name} ({upper}) must be above {minimum_name} ({minimum})"
+                );
+            }
+        }
+
+        for (name, value) in [
+            (
+                "floor_heat_transfer_coefficient",
+                self.floor_heat_transfer_coefficient,
+            ),
+            (
+                "wall_heat_transfer_coefficient",
+                self.wall_heat_transfer_coefficient,
+            ),
+            (
+                "open_heat_transfer_coefficient",
+                self.open_heat_transfer_coefficient,
+            ),
+            (
+                "window_heat_transfer_coefficient",
+                self.window_heat_transfer_coefficient,
+            ),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                eyre::bail!("Config entry {name} must be within 0..=1, got {value}");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// ### Description
+    /// Runtime version of [`MINIMUM_AIR_TO_SUSPEND`].
+    #[must_use]
+    #[inline(always)]
+    pub fn minimum_air_to_suspend(&self) -> Float {
+        MOLES_CELLSTANDARD * self.minimum_air_ratio_to_suspend
+    }
+
+    /// ### Description
+    /// Runtime version of [`MINIMUM_MOLES_DELTA_TO_MOVE`].
+    #[must_use]
+    #[inline(always)]
+    pub fn minimum_moles_delta_to_move(&self) -> Float {
+        MOLES_CELLSTANDARD * self.minimum_air_ratio_to_suspend
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
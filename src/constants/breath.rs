@@ -0,0 +1,21 @@
+use crate::utils::Float;
+
+/// Maximum oxygen loss dealt per breath when suffocating.
+pub const HUMAN_MAX_OXYLOSS: Float = 3.0;
+/// Oxygen loss healed per breath when the breath gas is above the safe minimum.
+pub const HUMAN_OXYLOSS_RECOVERY: Float = 5.0;
+pub const MIN_TOXIC_GAS_DAMAGE: Float = 1.0;
+pub const MAX_TOXIC_GAS_DAMAGE: Float = 10.0;
+/// Paralysis applied when sleeping agent partial pressure is above the paralysis threshold.
+pub const SLEEPING_AGENT_PARALYSIS: Float = 3.0;
+/// Sleep applied when sleeping agent partial pressure is above the sleep threshold.
+pub const SLEEPING_AGENT_SLEEP: Float = 10.0;
+
+// Default species breath limits, all of them are partial pressures in kPa. `0.0` disables the check.
+pub const DEFAULT_SAFE_OXYGEN_MIN: Float = 16.0;
+pub const DEFAULT_SAFE_OXYGEN_MAX: Float = 0.0;
+pub const DEFAULT_SAFE_CO2_MAX: Float = 10.0;
+pub const DEFAULT_SAFE_TOXINS_MAX: Float = 0.05;
+pub const DEFAULT_SAFE_AGENT_B_MAX: Float = 1.0;
+pub const DEFAULT_SLEEPING_AGENT_PARA_MIN: Float = 1.0;
+pub const DEFAULT_SLEEPING_AGENT_SLEEP_MIN: Float = 5.0;
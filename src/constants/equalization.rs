@@ -0,0 +1,6 @@
+use super::*;
+
+/// Minimum pressure difference (kPa) between neighbouring turfs to equalize their region in one pass.
+pub const EQUALIZATION_MINIMUM_PRESSURE_DIFFERENCE: Float = ONE_ATMOSPHERE * 0.1;
+/// Maximum number of turfs in a single equalized region, the rest of the turfs share as usual.
+pub const EQUALIZATION_MAX_REGION_SIZE: usize = 2000;
@@ -1,7 +1,11 @@
-pub const SPECIFIC_HEAT_TOXIN: f32 = 200.0;
-pub const SPECIFIC_HEAT_AIR: f32 = 20.0;
-pub const SPECIFIC_HEAT_CDO: f32 = 30.0;
-pub const SPECIFIC_HEAT_N2O: f32 = 40.0;
-pub const SPECIFIC_HEAT_AGENT_B: f32 = 300.0;
+use crate::utils::Float;
 
-pub const MINIMUM_HEAT_CAPACITY: f32 = 0.0003;
+pub const SPECIFIC_HEAT_TOXIN: Float = 200.0;
+pub const SPECIFIC_HEAT_HYDROGEN: Float = 15.0;
+pub const SPECIFIC_HEAT_AIR: Float = 20.0;
+pub const SPECIFIC_HEAT_CDO: Float = 30.0;
+pub const SPECIFIC_HEAT_N2O: Float = 40.0;
+pub const SPECIFIC_HEAT_AGENT_B: Float = 300.0;
+pub const SPECIFIC_HEAT_WATER_VAPOUR: Float = 40.0;
+
+pub const MINIMUM_HEAT_CAPACITY: Float = 0.0003;
@@ -0,0 +1,10 @@
+use super::T0C;
+use crate::utils::Float;
+
+pub const HYDROGEN_BURN_RATE_DELTA: Float = 2.0;
+pub const HYDROGEN_MINIMUM_BURN_TEMPERATURE: Float = 200.0 + T0C;
+pub const HYDROGEN_UPPER_TEMPERATURE: Float = 1000.0 + T0C;
+pub const HYDROGEN_OXYGEN_FULLBURN: Float = 2.0;
+/// Moles of `oxygen` burnt per mole of `hydrogen` (2 H2 + O2 -> 2 H2O).
+pub const HYDROGEN_OXYGEN_BURN_RATE: Float = 0.5;
+pub const FIRE_HYDROGEN_ENERGY_RELEASED: Float = 1200000.0;
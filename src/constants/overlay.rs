@@ -0,0 +1,6 @@
+use crate::utils::Float;
+
+/// `toxins` overlay is shown on a turf with more moles than it.
+pub const MOLES_TOXINS_VISIBLE: Float = 0.5;
+/// `sleeping_agent` overlay is shown on a turf with more moles than it.
+pub const MOLES_SLEEPING_AGENT_VISIBLE: Float = 1.0;
@@ -1,9 +1,10 @@
 use super::T0C;
+use crate::utils::Float;
 
-pub const OXYGEN_BURN_RATE_BASE: f32 = 1.4;
-pub const PLASMA_BURN_RATE_DELTA: f32 = 4.0;
-pub const PLASMA_MINIMUM_BURN_TEMPERATURE: f32 = 100.0 + T0C;
-pub const PLASMA_UPPER_TEMPERATURE: f32 = 1370.0 + T0C;
-pub const PLASMA_MINIMUM_OXYGEN_NEEDED: f32 = 2.0;
-pub const PLASMA_MINIMUM_OXYGEN_PLASMA_RATIO: f32 = 30.0;
-pub const PLASMA_OXYGEN_FULLBURN: f32 = 10.0;
+pub const OXYGEN_BURN_RATE_BASE: Float = 1.4;
+pub const PLASMA_BURN_RATE_DELTA: Float = 4.0;
+pub const PLASMA_MINIMUM_BURN_TEMPERATURE: Float = 100.0 + T0C;
+pub const PLASMA_UPPER_TEMPERATURE: Float = 1370.0 + T0C;
+pub const PLASMA_MINIMUM_OXYGEN_NEEDED: Float = 2.0;
+pub const PLASMA_MINIMUM_OXYGEN_PLASMA_RATIO: Float = 30.0;
+pub const PLASMA_OXYGEN_FULLBURN: Float = 10.0;
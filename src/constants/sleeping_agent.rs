@@ -0,0 +1,11 @@
+use super::T0C;
+use crate::utils::Float;
+
+/// `sleeping_agent` starts to decompose into `nitrogen` and `oxygen` above it.
+pub const SLEEPING_AGENT_DECOMPOSITION_MINIMUM_TEMPERATURE: Float = 1100.0 + T0C;
+/// The decomposition runs at the full rate above it.
+pub const SLEEPING_AGENT_DECOMPOSITION_UPPER_TEMPERATURE: Float = 2500.0 + T0C;
+/// Share of `sleeping_agent` decomposed per reaction at the full rate is `1 / delta`.
+pub const SLEEPING_AGENT_DECOMPOSITION_RATE_DELTA: Float = 4.0;
+/// Energy released per mole of decomposed `sleeping_agent`.
+pub const SLEEPING_AGENT_DECOMPOSITION_ENERGY_RELEASED: Float = 200000.0;
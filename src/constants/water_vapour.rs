@@ -0,0 +1,16 @@
+use super::T0C;
+use crate::utils::Float;
+
+/// Coefficients of the Magnus formula for the saturation vapour pressure over water, in kPa and degC.
+pub const WATER_VAPOUR_MAGNUS_PRESSURE: Float = 0.61094;
+pub const WATER_VAPOUR_MAGNUS_A: Float = 17.625;
+pub const WATER_VAPOUR_MAGNUS_B: Float = 243.04;
+/// The Magnus formula is meaningless far below freezing, all the `water_vapour` freezes out below it.
+pub const WATER_VAPOUR_FREEZING_TEMPERATURE: Float = T0C - 100.0;
+/// Smaller excesses over the saturation stay in the air, so a turf isn't made wet every tick by float noise.
+pub const WATER_VAPOUR_MINIMUM_CONDENSATION: Float = 0.1;
+/// Molar heat of vaporisation of water in J/mol, released into the mixture by the condensed `water_vapour`.
+pub const WATER_VAPOUR_LATENT_HEAT: Float = 40_700.0;
+/// Part of the excess over the saturation condensed per tick, so a mixture cooled below freezing
+/// doesn't lose all of its `water_vapour` at once.
+pub const WATER_VAPOUR_CONDENSATION_RATE: Float = 0.5;
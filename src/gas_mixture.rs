@@ -1,10 +1,30 @@
 mod adds;
+mod analyzer;
+mod archive;
+mod arithmetic;
+mod breathing;
+mod column;
+mod condensation;
+mod debug;
+mod equalize;
+mod gas;
+mod generation;
 mod getters;
+mod ideal_gas;
+mod overlay;
 mod procs;
+mod sanitization;
 mod setters;
 mod subs;
 
+pub use gas::Gas;
+pub use overlay::Overlays;
+
+use crate::config::CONFIG;
 use crate::constants::*;
+use crate::stats::STATS;
+use crate::utils::Float;
+use column::Column;
 
 use once_cell::unsync::Lazy;
 
@@ -21,36 +41,44 @@ static mut IS_INITIALIZED_MIXTURES: bool = false;
 
 #[derive(Debug, Default)]
 pub struct Mixture {
-    oxygen: Vec<f32>,
-    carbon_dioxide: Vec<f32>,
-    nitrogen: Vec<f32>,
-    toxins: Vec<f32>,
-    sleeping_agent: Vec<f32>,
-    agent_b: Vec<f32>,
-    volume: Vec<f32>,
-    temperature: Vec<f32>, //in Kelvin
-    last_share: Vec<f32>,
-    oxygen_archived: Vec<f32>,
-    carbon_dioxide_archived: Vec<f32>,
-    nitrogen_archived: Vec<f32>,
-    toxins_archived: Vec<f32>,
-    sleeping_agent_archived: Vec<f32>,
-    agent_b_archived: Vec<f32>,
-    temperature_archived: Vec<f32>,
-    fuel_burnt: Vec<f32>,
-    is_initialized: Vec<bool>,
+    oxygen: Column<Float>,
+    carbon_dioxide: Column<Float>,
+    nitrogen: Column<Float>,
+    toxins: Column<Float>,
+    hydrogen: Column<Float>,
+    sleeping_agent: Column<Float>,
+    agent_b: Column<Float>,
+    water_vapour: Column<Float>,
+    volume: Column<Float>,
+    temperature: Column<Float>, //in Kelvin
+    last_share: Column<Float>,
+    oxygen_archived: Column<Float>,
+    carbon_dioxide_archived: Column<Float>,
+    nitrogen_archived: Column<Float>,
+    toxins_archived: Column<Float>,
+    hydrogen_archived: Column<Float>,
+    sleeping_agent_archived: Column<Float>,
+    agent_b_archived: Column<Float>,
+    water_vapour_archived: Column<Float>,
+    temperature_archived: Column<Float>,
+    fuel_burnt: Column<Float>,
+    overlays: Column<Overlays>,
+    /// Generation of the last change of the air of each slot, see [`Mixture::mark_changed`].
+    generations: Column<u64>,
+    generation: u64,
+    /// Ids stamped with the current generation, each one once, see [`Mixture::get_changed_since`].
+    changed: Vec<usize>,
+    is_initialized: Column<bool>,
 }
 
 impl Mixture {
     /// ### Description
-    /// Pre-allocates a 1_000_000 `gas_mixtures`.
-    /// Needs for avoding a reallocation a whole [`GAS_MIXTURES`] with each creaing of an instance of `gas_mixture`.
-    /// ### Size
-    /// Initial size of [`MIXTURES`] is 68 MBs.
-    const DEFAULT_ALLOCATED_GAS_MIXTURES_COUNT: usize = 1_000_000;
+    /// Maximum number of `gas_mixtures`, every column reserves 1_000_000 slots.
+    /// Only the pages of the registered slots are allocated, see [`Column`].
+    const MAX_GAS_MIXTURES_COUNT: usize = 1_000_000;
     /// #### Description
     /// Liters in a cell.
-    const CELL_VOLUME: f32 = 2500.0;
+    const CELL_VOLUME: Float = 2500.0;
 
     #[must_use]
     #[inline(always)]
@@ -59,44 +87,65 @@ impl Mixture {
             panic!("`MIXTURES` is already initialized!");
         }
 
-        let vec_of_zeros = vec![Default::default(); Self::DEFAULT_ALLOCATED_GAS_MIXTURES_COUNT];
-        let vec_of_cell_volumes =
-            vec![Self::CELL_VOLUME; Self::DEFAULT_ALLOCATED_GAS_MIXTURES_COUNT];
-        let vec_of_bools = vec![Default::default(); Self::DEFAULT_ALLOCATED_GAS_MIXTURES_COUNT];
-
         unsafe {
             IS_INITIALIZED_MIXTURES = true;
         }
 
         Self {
-            oxygen: vec_of_zeros.clone(),
-            carbon_dioxide: vec_of_zeros.clone(),
-            nitrogen: vec_of_zeros.clone(),
-            toxins: vec_of_zeros.clone(),
-            sleeping_agent: vec_of_zeros.clone(),
-            agent_b: vec_of_zeros.clone(),
-            volume: vec_of_cell_volumes,
-            temperature: vec_of_zeros.clone(),
-            last_share: vec_of_zeros.clone(),
-            oxygen_archived: vec_of_zeros.clone(),
-            carbon_dioxide_archived: vec_of_zeros.clone(),
-            nitrogen_archived: vec_of_zeros.clone(),
-            toxins_archived: vec_of_zeros.clone(),
-            sleeping_agent_archived: vec_of_zeros.clone(),
-            agent_b_archived: vec_of_zeros.clone(),
-            temperature_archived: vec_of_zeros.clone(),
-            fuel_burnt: vec_of_zeros,
-            is_initialized: vec_of_bools,
+            oxygen: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            carbon_dioxide: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            nitrogen: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            toxins: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            hydrogen: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            sleeping_agent: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            agent_b: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            water_vapour: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            volume: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Self::CELL_VOLUME),
+            temperature: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            last_share: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            oxygen_archived: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            carbon_dioxide_archived: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            nitrogen_archived: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            toxins_archived: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            hydrogen_archived: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            sleeping_agent_archived: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            agent_b_archived: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            water_vapour_archived: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            temperature_archived: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            fuel_burnt: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            overlays: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            generations: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
+            generation: Self::FIRST_GENERATION,
+            changed: Vec::new(),
+            is_initialized: Column::new(Self::MAX_GAS_MIXTURES_COUNT, Default::default()),
         }
     }
 
+    /// ### Description
+    /// Allocates the page of the `src` slot in every column and marks it as initialized.
     #[inline(always)]
     pub unsafe fn register(&mut self, src: usize) {
+        self.allocate(src);
+
+        if !self.get_is_initialized(src) {
+            STATS.registered_mixtures += 1;
+        }
+
         self.set_is_initialized(src, true);
     }
 
+    /// ### Description
+    /// Resets the `src` slot to the defaults, does nothing if its page was never allocated.
     #[inline(always)]
     pub unsafe fn unregister(&mut self, src: usize) {
+        if !self.is_allocated(src) {
+            return;
+        }
+
+        if self.get_is_initialized(src) {
+            STATS.registered_mixtures -= 1;
+        }
+
         self.set_to_default(src);
     }
 
@@ -108,8 +157,10 @@ impl Mixture {
         self.set_carbon_dioxide(id, Default::default());
         self.set_nitrogen(id, Default::default());
         self.set_toxins(id, Default::default());
+        self.set_hydrogen(id, Default::default());
         self.set_sleeping_agent(id, Default::default());
         self.set_agent_b(id, Default::default());
+        self.set_water_vapour(id, Default::default());
         self.set_volume(id, Self::CELL_VOLUME);
         self.set_temperature(id, Default::default()); //in Kelvin
         self.set_last_share(id, Default::default());
@@ -117,16 +168,66 @@ impl Mixture {
         self.set_carbon_dioxide_archived(id, Default::default());
         self.set_nitrogen_archived(id, Default::default());
         self.set_toxins_archived(id, Default::default());
+        self.set_hydrogen_archived(id, Default::default());
         self.set_sleeping_agent_archived(id, Default::default());
         self.set_agent_b_archived(id, Default::default());
+        self.set_water_vapour_archived(id, Default::default());
         self.set_temperature_archived(id, Default::default());
         self.set_fuel_burnt(id, Default::default());
+        self.set_overlays(id, Default::default());
+    }
+
+    /// ### Description
+    /// Number of slots, ids from `DM` must be less than it.
+    #[must_use]
+    #[inline(always)]
+    pub fn slots_count(&self) -> usize {
+        self.is_initialized.slots_count()
+    }
+
+    /// ### Description
+    /// Whether the page of the `id` slot is allocated, i.e. a slot of the page was registered.
+    /// The columns of a slot may be accessed only if it is.
+    #[must_use]
+    #[inline(always)]
+    pub fn is_allocated(&self, id: usize) -> bool {
+        self.is_initialized.is_allocated(id)
     }
 
+    #[inline(always)]
+    fn allocate(&mut self, id: usize) {
+        self.oxygen.allocate(id);
+        self.carbon_dioxide.allocate(id);
+        self.nitrogen.allocate(id);
+        self.toxins.allocate(id);
+        self.hydrogen.allocate(id);
+        self.sleeping_agent.allocate(id);
+        self.agent_b.allocate(id);
+        self.water_vapour.allocate(id);
+        self.volume.allocate(id);
+        self.temperature.allocate(id);
+        self.last_share.allocate(id);
+        self.oxygen_archived.allocate(id);
+        self.carbon_dioxide_archived.allocate(id);
+        self.nitrogen_archived.allocate(id);
+        self.toxins_archived.allocate(id);
+        self.hydrogen_archived.allocate(id);
+        self.sleeping_agent_archived.allocate(id);
+        self.agent_b_archived.allocate(id);
+        self.water_vapour_archived.allocate(id);
+        self.temperature_archived.allocate(id);
+        self.fuel_burnt.allocate(id);
+        self.overlays.allocate(id);
+        self.generations.allocate(id);
+        self.is_initialized.allocate(id);
+    }
+
+    /// ### Description
+    /// `false` for the slots whose page isn't allocated, so it's safe to call with any id.
     #[inline(always)]
     #[must_use]
     pub unsafe fn get_is_initialized(&self, id: usize) -> bool {
-        *self.is_initialized.get_unchecked(id)
+        self.is_allocated(id) && *self.is_initialized.get_unchecked(id)
     }
 
     #[inline(always)]
@@ -134,17 +235,33 @@ impl Mixture {
         *self.is_initialized.get_unchecked_mut(id) = src;
     }
 
+    /// ### Description
+    /// Overlays of the `id` mixture as of the last [`Mixture::update_overlays`].
+    #[inline(always)]
+    #[must_use]
+    pub unsafe fn get_overlays(&self, id: usize) -> Overlays {
+        *self.overlays.get_unchecked(id)
+    }
+
+    #[inline(always)]
+    pub unsafe fn set_overlays(&mut self, id: usize, src: Overlays) {
+        *self.overlays.get_unchecked_mut(id) = src;
+    }
+
     #[must_use]
     #[inline(always)]
-    fn compare_condition(self_value: f32, sample_value: f32) -> bool {
-        ((self_value - sample_value).abs() > MINIMUM_AIR_TO_SUSPEND)
-            && ((self_value < (1.0 - MINIMUM_AIR_RATIO_TO_SUSPEND) * sample_value)
-                || (self_value > (1.0 + MINIMUM_AIR_RATIO_TO_SUSPEND) * sample_value))
+    unsafe fn compare_condition(self_value: Float, sample_value: Float) -> bool {
+        let minimum_air_ratio_to_suspend = CONFIG.minimum_air_ratio_to_suspend;
+
+        ((self_value - sample_value).abs() > CONFIG.minimum_air_to_suspend())
+            && ((self_value < (1.0 - minimum_air_ratio_to_suspend) * sample_value)
+                || (self_value > (1.0 + minimum_air_ratio_to_suspend) * sample_value))
     }
 
     #[must_use]
     #[inline(always)]
-    fn check_turf_condition(value_01: f32, value_02: f32) -> bool {
-        (value_01 > MINIMUM_AIR_TO_SUSPEND) && (value_01 >= value_02 * MINIMUM_AIR_RATIO_TO_SUSPEND)
+    unsafe fn check_turf_condition(value_01: Float, value_02: Float) -> bool {
+        (value_01 > CONFIG.minimum_air_to_suspend())
+            && (value_01 >= value_02 * CONFIG.minimum_air_ratio_to_suspend)
     }
 }
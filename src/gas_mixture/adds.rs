@@ -1,12 +1,16 @@
 use super::Mixture;
+use crate::utils::Float;
 
 macro_rules! add_methods {
     ($($method:ident, $field:ident);+ $(;)?) => {
         impl Mixture {
             $(
                 #[inline(always)]
-                pub unsafe fn $method(&mut self, id: usize, value: f32) {
-                    *self.$field.get_unchecked_mut(id) += value;
+                pub unsafe fn $method(&mut self, id: usize, value: Float) {
+                    if value != Default::default() {
+                        *self.$field.get_unchecked_mut(id) += value;
+                        self.mark_changed(id);
+                    }
                 }
             )+
         }
@@ -18,8 +22,18 @@ add_methods! {
     add_carbon_dioxide, carbon_dioxide;
     add_nitrogen, nitrogen;
     add_toxins, toxins;
+    add_hydrogen, hydrogen;
     add_sleeping_agent, sleeping_agent;
     add_agent_b, agent_b;
+    add_water_vapour, water_vapour;
     add_temperature, temperature;
-    add_fuel_burnt, fuel_burnt;
+}
+
+impl Mixture {
+    /// ### Description
+    /// `fuel_burnt` is bookkeeping of [`Mixture::react`], so it doesn't mark the mixture as changed.
+    #[inline(always)]
+    pub unsafe fn add_fuel_burnt(&mut self, id: usize, value: Float) {
+        *self.fuel_burnt.get_unchecked_mut(id) += value;
+    }
 }
@@ -0,0 +1,122 @@
+use super::{Gas, Mixture};
+use crate::config::CONFIG;
+use crate::constants::*;
+use crate::profile;
+use crate::utils::{to_number, Float};
+use byondapi::value::ByondValue;
+
+/// ### Description
+/// Readout of a single gas of [`AnalyzerReport`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GasReading {
+    pub moles: Float,
+    pub percentage: Float,
+    pub partial_pressure: Float,
+}
+
+impl GasReading {
+    const MOLES: &'static str = "moles";
+    const PERCENTAGE: &'static str = "percentage";
+    const PARTIAL_PRESSURE: &'static str = "partial_pressure";
+
+    pub fn to_list(self) -> eyre::Result<ByondValue> {
+        let mut list = ByondValue::new_list()?;
+        list.write_list_index(Self::MOLES, to_number(self.moles))?;
+        list.write_list_index(Self::PERCENTAGE, to_number(self.percentage))?;
+        list.write_list_index(Self::PARTIAL_PRESSURE, to_number(self.partial_pressure))?;
+
+        Ok(list)
+    }
+}
+
+/// ### Description
+/// Everything that gas analyzers, air alarms and PDAs show about a mixture.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AnalyzerReport {
+    pub pressure: Float,
+    pub temperature: Float,
+    pub volume: Float,
+    pub total_moles: Float,
+    pub heat_capacity: Float,
+    pub thermal_energy: Float,
+    /// Moles of the gases that analyzers show as "unknown".
+    pub trace_moles: Float,
+    /// `true` if the mixture has enough plasma and oxygen to start a fire when ignited.
+    pub fire_risk: bool,
+    /// Readouts in the order of [`Gas::ALL`].
+    pub gases: [GasReading; Gas::ALL.len()],
+}
+
+impl AnalyzerReport {
+    const PRESSURE: &'static str = "pressure";
+    const TEMPERATURE: &'static str = "temperature";
+    const VOLUME: &'static str = "volume";
+    const TOTAL_MOLES: &'static str = "total_moles";
+    const HEAT_CAPACITY: &'static str = "heat_capacity";
+    const THERMAL_ENERGY: &'static str = "thermal_energy";
+    const TRACE_MOLES: &'static str = "trace_moles";
+    const FIRE_RISK: &'static str = "fire_risk";
+    const GASES: &'static str = "gases";
+
+    /// ### Description
+    /// Converts the report to an associative `DM` list.
+    /// Per-gas readouts are placed in the `gases` list, keyed by the name of the gas.
+    pub fn to_list(self) -> eyre::Result<ByondValue> {
+        let mut gases = ByondValue::new_list()?;
+        for (gas, reading) in Gas::ALL.into_iter().zip(self.gases) {
+            gases.write_list_index(gas.name(), reading.to_list()?)?;
+        }
+
+        let mut list = ByondValue::new_list()?;
+        list.write_list_index(Self::PRESSURE, to_number(self.pressure))?;
+        list.write_list_index(Self::TEMPERATURE, to_number(self.temperature))?;
+        list.write_list_index(Self::VOLUME, to_number(self.volume))?;
+        list.write_list_index(Self::TOTAL_MOLES, to_number(self.total_moles))?;
+        list.write_list_index(Self::HEAT_CAPACITY, to_number(self.heat_capacity))?;
+        list.write_list_index(Self::THERMAL_ENERGY, to_number(self.thermal_energy))?;
+        list.write_list_index(Self::TRACE_MOLES, to_number(self.trace_moles))?;
+        list.write_list_index(Self::FIRE_RISK, self.fire_risk)?;
+        list.write_list_index(Self::GASES, gases)?;
+
+        Ok(list)
+    }
+}
+
+impl Mixture {
+    #[must_use]
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    pub unsafe fn analyze(&self, id: usize) -> AnalyzerReport {
+        profile!("analyze");
+
+        let pressure = self.return_pressure(id);
+        let total_moles = self.total_moles(id);
+
+        let mut gases = [GasReading::default(); Gas::ALL.len()];
+        if total_moles > Default::default() {
+            for (gas, reading) in Gas::ALL.into_iter().zip(gases.iter_mut()) {
+                let moles = self.get_gas(id, gas);
+                let ratio = moles / total_moles;
+
+                *reading = GasReading {
+                    moles,
+                    percentage: ratio * 100.0,
+                    partial_pressure: ratio * pressure,
+                };
+            }
+        }
+
+        AnalyzerReport {
+            pressure,
+            temperature: self.get_temperature(id),
+            volume: self.get_volume(id),
+            total_moles,
+            heat_capacity: self.heat_capacity(id),
+            thermal_energy: self.thermal_energy(id),
+            trace_moles: self.get_total_trace_moles(id),
+            fire_risk: self.get_toxins(id) > MINIMUM_HEAT_CAPACITY
+                && self.get_oxygen(id) > CONFIG.plasma_minimum_oxygen_needed,
+            gases,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::ops::Range;
+
+use super::Mixture;
+use crate::profile;
+
+impl Mixture {
+    /// ### Description
+    /// Archives many mixtures at once: sorts `ids` and archives every contiguous run of them with [`Mixture::archive_range`].
+    /// Duplicated ids are fine. Same as calling [`Mixture::archive`] for each id, but turfs of a map are mostly
+    /// allocated one after another, so it ends up as a few bulk copies instead of a copy per column per id.
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    pub unsafe fn archive_many(&mut self, ids: &mut [usize]) {
+        profile!("archive_many");
+
+        ids.sort_unstable();
+
+        let mut start = 0;
+        while start < ids.len() {
+            let mut end = start + 1;
+            while end < ids.len() && ids[end] <= ids[end - 1] + 1 {
+                end += 1;
+            }
+
+            self.archive_range(ids[start]..ids[end - 1] + 1);
+            start = end;
+        }
+    }
+
+    /// ### Description
+    /// Archives the slots in `range` with a `copy_from_slice` per column and page.
+    #[inline(always)]
+    pub unsafe fn archive_range(&mut self, range: Range<usize>) {
+        self.oxygen_archived
+            .copy_range_from(&self.oxygen, range.clone());
+        self.carbon_dioxide_archived
+            .copy_range_from(&self.carbon_dioxide, range.clone());
+        self.nitrogen_archived
+            .copy_range_from(&self.nitrogen, range.clone());
+        self.toxins_archived
+            .copy_range_from(&self.toxins, range.clone());
+        self.hydrogen_archived
+            .copy_range_from(&self.hydrogen, range.clone());
+        self.sleeping_agent_archived
+            .copy_range_from(&self.sleeping_agent, range.clone());
+        self.agent_b_archived
+            .copy_range_from(&self.agent_b, range.clone());
+        self.water_vapour_archived
+            .copy_range_from(&self.water_vapour, range.clone());
+        self.temperature_archived
+            .copy_range_from(&self.temperature, range);
+    }
+}
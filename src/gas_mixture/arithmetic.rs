@@ -0,0 +1,57 @@
+use super::{Gas, Mixture};
+use crate::profile;
+use crate::utils::{quantize, Float};
+
+impl Mixture {
+    /// ### Description
+    /// Multiplies every gas of the `id` mixture by `factor`, non-positive `factor` empties it.
+    /// The temperature is kept, so the heat energy scales together with the moles.
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    pub unsafe fn scale(&mut self, id: usize, factor: Float) {
+        profile!("scale");
+
+        let factor = factor.max(Default::default());
+        for gas in Gas::ALL {
+            self.set_gas(id, gas, quantize(self.get_gas(id, gas) * factor));
+        }
+    }
+
+    /// ### Description
+    /// Moves all the air of the `id` mixture into the `parts` mixtures in equal shares with [`Mixture::remove_ratio`],
+    /// the last part takes whatever quantization has left. Every part gets `1 / parts.len()` of the volume,
+    /// so it keeps the pressure and the temperature of the source.
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    pub unsafe fn split(&mut self, id: usize, parts: &[usize]) {
+        profile!("split");
+
+        let volume = self.get_volume(id) / parts.len() as Float;
+        for (index, &part_id) in parts.iter().enumerate() {
+            self.remove_ratio(id, part_id, 1.0 / (parts.len() - index) as Float);
+            self.set_volume(part_id, volume);
+        }
+    }
+
+    /// ### Description
+    /// Carves `liters` out of the `id` mixture into the `removed_id` one with [`Mixture::remove_ratio`].
+    /// The removed mixture gets the carved volume, so it keeps the pressure and the temperature of the source.
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    pub unsafe fn remove_volume(&mut self, id: usize, removed_id: usize, liters: Float) {
+        profile!("remove_volume");
+
+        let volume = self.get_volume(id);
+        let liters = liters.clamp(Default::default(), volume.max(Default::default()));
+        let ratio = if volume > Default::default() {
+            liters / volume
+        } else {
+            Default::default()
+        };
+
+        self.remove_ratio(id, removed_id, ratio);
+        if ratio > Default::default() {
+            self.set_volume(removed_id, liters);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use super::{Gas, Mixture};
+use crate::profile;
+use crate::utils::{to_number, Float};
+use crate::{constants::*, species::SpeciesProfile};
+use byondapi::value::ByondValue;
+
+/// ### Description
+/// Effects of a single breath on a mob, applied by `DM`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BreathResult {
+    /// Oxygen loss to apply. Negative value means that the mob recovers.
+    pub suffocation: Float,
+    pub toxin_damage: Float,
+    pub paralysis: Float,
+    pub sleep: Float,
+    /// How much the `carbon_dioxide` partial pressure exceeds the species limit, `0.0` if it doesn't.
+    pub co2_buildup: Float,
+}
+
+impl BreathResult {
+    const SUFFOCATION: &'static str = "suffocation";
+    const TOXIN_DAMAGE: &'static str = "toxin_damage";
+    const PARALYSIS: &'static str = "paralysis";
+    const SLEEP: &'static str = "sleep";
+    const CO2_BUILDUP: &'static str = "co2_buildup";
+
+    /// ### Description
+    /// Converts the result to an associative `DM` list.
+    pub fn to_list(self) -> eyre::Result<ByondValue> {
+        let mut list = ByondValue::new_list()?;
+        list.write_list_index(Self::SUFFOCATION, to_number(self.suffocation))?;
+        list.write_list_index(Self::TOXIN_DAMAGE, to_number(self.toxin_damage))?;
+        list.write_list_index(Self::PARALYSIS, to_number(self.paralysis))?;
+        list.write_list_index(Self::SLEEP, to_number(self.sleep))?;
+        list.write_list_index(Self::CO2_BUILDUP, to_number(self.co2_buildup))?;
+
+        Ok(list)
+    }
+}
+
+impl Mixture {
+    /// ### Description
+    /// Simulates a breath of the `id` mixture by a mob of `species`.
+    /// Consumes the breathed gas and exhales the same amount of `exhale_type` back into the mixture.
+    #[must_use]
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    pub unsafe fn breathe(&mut self, id: usize, species: &SpeciesProfile) -> BreathResult {
+        profile!("breathe");
+
+        let mut result = BreathResult::default();
+
+        if self.total_moles(id) <= Default::default() {
+            result.suffocation = HUMAN_MAX_OXYLOSS;
+
+            return result;
+        }
+
+        let breath_moles = self.get_gas(id, species.breath_type);
+        let breath_pp = self.get_breath_partial_pressure(id, breath_moles);
+
+        let breath_used;
+        if species.safe_breath_min > 0.0 && breath_pp < species.safe_breath_min {
+            if breath_pp > 0.0 {
+                let ratio = 1.0 - breath_pp / species.safe_breath_min;
+                result.suffocation = (5.0 * ratio).min(HUMAN_MAX_OXYLOSS);
+                breath_used = breath_moles * ratio;
+            } else {
+                result.suffocation = HUMAN_MAX_OXYLOSS;
+                breath_used = Default::default();
+            }
+        } else {
+            result.suffocation = -HUMAN_OXYLOSS_RECOVERY;
+            breath_used = breath_moles;
+        }
+
+        if species.safe_breath_max > 0.0 && breath_pp > species.safe_breath_max {
+            result.toxin_damage += Self::toxic_gas_damage(breath_moles, species.safe_breath_max);
+        }
+
+        self.sub_gas(id, species.breath_type, breath_used);
+        self.add_gas(id, species.exhale_type, breath_used);
+
+        let carbon_dioxide_pp = self.get_breath_partial_pressure(id, self.get_carbon_dioxide(id));
+        if species.safe_co2_max > 0.0 && carbon_dioxide_pp > species.safe_co2_max {
+            result.co2_buildup = carbon_dioxide_pp - species.safe_co2_max;
+        }
+
+        let toxins = self.get_toxins(id);
+        if species.safe_toxins_max > 0.0
+            && self.get_breath_partial_pressure(id, toxins) > species.safe_toxins_max
+        {
+            result.toxin_damage += Self::toxic_gas_damage(toxins, species.safe_toxins_max);
+        }
+
+        let agent_b = self.get_agent_b(id);
+        if species.safe_agent_b_max > 0.0
+            && self.get_breath_partial_pressure(id, agent_b) > species.safe_agent_b_max
+        {
+            result.toxin_damage += Self::toxic_gas_damage(agent_b, species.safe_agent_b_max);
+        }
+
+        let sleeping_agent_pp = self.get_breath_partial_pressure(id, self.get_sleeping_agent(id));
+        if sleeping_agent_pp > species.sleeping_agent_para_min {
+            result.paralysis = SLEEPING_AGENT_PARALYSIS;
+
+            if sleeping_agent_pp > species.sleeping_agent_sleep_min {
+                result.sleep = SLEEPING_AGENT_SLEEP;
+            }
+        }
+
+        result
+    }
+
+    #[must_use]
+    #[inline(always)]
+    fn toxic_gas_damage(moles: Float, safe_max: Float) -> Float {
+        (moles / safe_max * 10.0).clamp(MIN_TOXIC_GAS_DAMAGE, MAX_TOXIC_GAS_DAMAGE)
+    }
+}
@@ -0,0 +1,167 @@
+use std::ops::Range;
+
+/// ### Description
+/// Column of [`super::Mixture`], split into pages of [`PAGE_SIZE`] slots that are allocated on the first use.
+/// Small maps touch a few pages, so they don't pay for the slots they never register.
+/// ### Safety
+/// The unchecked accessors require the page of the slot to be allocated, see [`Column::allocate`].
+#[derive(Debug, Default)]
+pub struct Column<T> {
+    pages: Vec<Option<Box<[T]>>>,
+    default: T,
+}
+
+pub const PAGE_SIZE: usize = 4096;
+const PAGE_SHIFT: u32 = PAGE_SIZE.trailing_zeros();
+const PAGE_MASK: usize = PAGE_SIZE - 1;
+
+impl<T: Copy> Column<T> {
+    /// ### Description
+    /// Creates a column of `slots_count` slots without allocating any page.
+    /// Slots of a new page are set to `default`.
+    #[must_use]
+    pub fn new(slots_count: usize, default: T) -> Self {
+        Self {
+            pages: vec![None; slots_count.div_ceil(PAGE_SIZE)],
+            default,
+        }
+    }
+
+    #[must_use]
+    #[inline(always)]
+    pub fn slots_count(&self) -> usize {
+        self.pages.len() * PAGE_SIZE
+    }
+
+    #[must_use]
+    #[inline(always)]
+    pub fn is_allocated(&self, id: usize) -> bool {
+        self.pages
+            .get(id >> PAGE_SHIFT)
+            .is_some_and(|page| page.is_some())
+    }
+
+    /// ### Description
+    /// Allocates the page of the `id` slot if it isn't allocated yet.
+    /// ### Panics
+    /// If `id` is out of [`Column::slots_count`].
+    #[inline(always)]
+    pub fn allocate(&mut self, id: usize) {
+        let page = &mut self.pages[id >> PAGE_SHIFT];
+        if page.is_none() {
+            *page = Some(vec![self.default; PAGE_SIZE].into_boxed_slice());
+        }
+    }
+
+    #[must_use]
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, id: usize) -> &T {
+        self.pages
+            .get_unchecked(id >> PAGE_SHIFT)
+            .as_deref()
+            .unwrap_unchecked()
+            .get_unchecked(id & PAGE_MASK)
+    }
+
+    #[must_use]
+    #[inline(always)]
+    pub unsafe fn get_unchecked_mut(&mut self, id: usize) -> &mut T {
+        self.pages
+            .get_unchecked_mut(id >> PAGE_SHIFT)
+            .as_deref_mut()
+            .unwrap_unchecked()
+            .get_unchecked_mut(id & PAGE_MASK)
+    }
+
+    /// ### Description
+    /// Copies the slots in `range` from `source` with a `copy_from_slice` per page.
+    /// Every page of the range must be allocated in both columns.
+    #[inline(always)]
+    pub unsafe fn copy_range_from(&mut self, source: &Self, range: Range<usize>) {
+        let mut start = range.start;
+        while start < range.end {
+            let page = start >> PAGE_SHIFT;
+            let end = range.end.min((page + 1) * PAGE_SIZE);
+            let slots = (start & PAGE_MASK)..((end - 1) & PAGE_MASK) + 1;
+
+            self.pages
+                .get_unchecked_mut(page)
+                .as_deref_mut()
+                .unwrap_unchecked()
+                .get_unchecked_mut(slots.clone())
+                .copy_from_slice(
+                    source
+                        .pages
+                        .get_unchecked(page)
+                        .as_deref()
+                        .unwrap_unchecked()
+                        .get_unchecked(slots),
+                );
+
+            start = end;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocate_allocates_only_the_page_of_the_slot() {
+        let mut column = Column::new(PAGE_SIZE * 3, 7u32);
+        assert_eq!(column.slots_count(), PAGE_SIZE * 3);
+        assert!(!column.is_allocated(0));
+
+        column.allocate(PAGE_SIZE + 1);
+
+        assert!(!column.is_allocated(PAGE_SIZE - 1));
+        assert!(column.is_allocated(PAGE_SIZE));
+        assert!(column.is_allocated(PAGE_SIZE * 2 - 1));
+        assert!(!column.is_allocated(PAGE_SIZE * 2));
+        assert!(!column.is_allocated(PAGE_SIZE * 3));
+        assert_eq!(unsafe { *column.get_unchecked(PAGE_SIZE) }, 7);
+    }
+
+    #[test]
+    fn allocate_keeps_the_values_of_an_allocated_page() {
+        let mut column = Column::new(PAGE_SIZE, 0u32);
+        column.allocate(0);
+        unsafe { *column.get_unchecked_mut(1) = 5 };
+
+        column.allocate(2);
+
+        assert_eq!(unsafe { *column.get_unchecked(1) }, 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn allocate_panics_out_of_slots() {
+        Column::new(PAGE_SIZE, 0u32).allocate(PAGE_SIZE);
+    }
+
+    #[test]
+    fn copy_range_from_crosses_pages() {
+        let mut source = Column::new(PAGE_SIZE * 3, 0usize);
+        let mut destination = Column::new(PAGE_SIZE * 3, 0usize);
+        for id in [0, PAGE_SIZE, PAGE_SIZE * 2] {
+            source.allocate(id);
+            destination.allocate(id);
+        }
+        for id in 0..PAGE_SIZE * 3 {
+            unsafe { *source.get_unchecked_mut(id) = id + 1 };
+        }
+
+        let range = PAGE_SIZE - 2..PAGE_SIZE * 2 + 3;
+        unsafe { destination.copy_range_from(&source, range.clone()) };
+
+        for id in 0..PAGE_SIZE * 3 {
+            let expected = if range.contains(&id) { id + 1 } else { 0 };
+            assert_eq!(
+                unsafe { *destination.get_unchecked(id) },
+                expected,
+                "slot {id}"
+            );
+        }
+    }
+}
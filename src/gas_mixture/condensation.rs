@@ -0,0 +1,60 @@
+use super::Mixture;
+use crate::constants::*;
+use crate::profile;
+use crate::utils::{quantize, Float};
+
+impl Mixture {
+    /// ### Description
+    /// Condenses the `water_vapour` that exceeds the saturation at the temperature of the mixture,
+    /// i.e. when the temperature has dropped below the dew point. The condensed water leaves the mixture
+    /// and heats it by [`WATER_VAPOUR_LATENT_HEAT`]. Only [`WATER_VAPOUR_CONDENSATION_RATE`] of the excess condenses per tick.
+    /// ### Returns
+    /// Moles of the condensed `water_vapour`, `0.0` if nothing has condensed.
+    #[must_use]
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    pub unsafe fn condense(&mut self, id: usize) -> Float {
+        profile!("condense");
+
+        let water_vapour = self.get_water_vapour(id);
+        let temperature = self.get_temperature(id);
+        if water_vapour <= Default::default() || temperature <= Default::default() {
+            return Default::default();
+        }
+
+        let saturated_moles = saturation_vapour_pressure(temperature) * self.get_volume(id)
+            / (R_IDEAL_GAS_EQUATION * temperature);
+        let excess = water_vapour - saturated_moles;
+        if excess < WATER_VAPOUR_MINIMUM_CONDENSATION {
+            return Default::default();
+        }
+
+        let condensed = quantize(excess * WATER_VAPOUR_CONDENSATION_RATE).min(water_vapour);
+        self.sub_water_vapour(id, condensed);
+
+        let heat_capacity = self.heat_capacity(id);
+        if heat_capacity > 0.0 {
+            self.set_temperature(
+                id,
+                temperature + condensed * WATER_VAPOUR_LATENT_HEAT / heat_capacity,
+            );
+        }
+
+        condensed
+    }
+}
+
+/// ### Description
+/// Saturation vapour pressure over water in kPa by the Magnus formula.
+#[must_use]
+#[inline(always)]
+fn saturation_vapour_pressure(temperature: Float) -> Float {
+    if temperature < WATER_VAPOUR_FREEZING_TEMPERATURE {
+        return Default::default();
+    }
+
+    let celsius = temperature - T0C;
+
+    WATER_VAPOUR_MAGNUS_PRESSURE
+        * (WATER_VAPOUR_MAGNUS_A * celsius / (celsius + WATER_VAPOUR_MAGNUS_B)).exp()
+}
@@ -0,0 +1,155 @@
+use super::{Gas, Mixture};
+use crate::constants::*;
+use crate::profile;
+use crate::utils::{to_number, Float};
+use byondapi::value::ByondValue;
+
+/// ### Description
+/// Raw values of every column of a slot, for admins to inspect the Rust side.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MixtureDump {
+    pub id: usize,
+    pub is_initialized: bool,
+    /// Moles in the order of [`Gas::ALL`].
+    pub gases: [Float; Gas::ALL.len()],
+    /// Archived moles in the order of [`Gas::ALL`].
+    pub gases_archived: [Float; Gas::ALL.len()],
+    pub volume: Float,
+    pub temperature: Float,
+    pub temperature_archived: Float,
+    pub last_share: Float,
+    pub fuel_burnt: Float,
+}
+
+impl MixtureDump {
+    const ID: &'static str = "id";
+    const IS_INITIALIZED: &'static str = "is_initialized";
+    const VOLUME: &'static str = "volume";
+    const TEMPERATURE: &'static str = "temperature";
+    const TEMPERATURE_ARCHIVED: &'static str = "temperature_archived";
+    const LAST_SHARE: &'static str = "last_share";
+    const FUEL_BURNT: &'static str = "fuel_burnt";
+
+    /// ### Description
+    /// Converts the dump to an associative `DM` list, keyed by the names of the columns.
+    pub fn to_list(self) -> eyre::Result<ByondValue> {
+        let mut list = ByondValue::new_list()?;
+        list.write_list_index(Self::ID, self.id as f32)?;
+        list.write_list_index(Self::IS_INITIALIZED, self.is_initialized)?;
+        for ((gas, moles), archived) in Gas::ALL
+            .into_iter()
+            .zip(self.gases)
+            .zip(self.gases_archived)
+        {
+            list.write_list_index(gas.name(), to_number(moles))?;
+            list.write_list_index(
+                format!("{}_archived", gas.name()).as_str(),
+                to_number(archived),
+            )?;
+        }
+        list.write_list_index(Self::VOLUME, to_number(self.volume))?;
+        list.write_list_index(Self::TEMPERATURE, to_number(self.temperature))?;
+        list.write_list_index(
+            Self::TEMPERATURE_ARCHIVED,
+            to_number(self.temperature_archived),
+        )?;
+        list.write_list_index(Self::LAST_SHARE, to_number(self.last_share))?;
+        list.write_list_index(Self::FUEL_BURNT, to_number(self.fuel_burnt))?;
+
+        Ok(list)
+    }
+}
+
+/// ### Description
+/// Broken invariant of a registered mixture, found by [`Mixture::check_invariants`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    /// A gas, the volume or the temperature is `NaN`.
+    Nan,
+    /// A gas, the volume or the temperature is infinite.
+    Infinity,
+    NegativeMoles,
+    NonPositiveVolume,
+    /// Temperature of a non-empty mixture is below [`TCMB`], empty mixtures are reset to `0` and are skipped.
+    TemperatureBelowTcmb,
+}
+
+impl Violation {
+    #[must_use]
+    #[inline(always)]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Nan => "nan",
+            Self::Infinity => "infinity",
+            Self::NegativeMoles => "negative_moles",
+            Self::NonPositiveVolume => "non_positive_volume",
+            Self::TemperatureBelowTcmb => "temperature_below_tcmb",
+        }
+    }
+}
+
+impl Mixture {
+    #[must_use]
+    pub unsafe fn dump(&self, id: usize) -> MixtureDump {
+        MixtureDump {
+            id,
+            is_initialized: self.get_is_initialized(id),
+            gases: Gas::ALL.map(|gas| self.get_gas(id, gas)),
+            gases_archived: Gas::ALL.map(|gas| self.get_gas_archived(id, gas)),
+            volume: self.get_volume(id),
+            temperature: self.get_temperature(id),
+            temperature_archived: self.get_temperature_archived(id),
+            last_share: self.get_last_share(id),
+            fuel_burnt: self.get_fuel_burnt(id),
+        }
+    }
+
+    /// ### Description
+    /// Broken invariants of the `id` mixture, each one is reported once.
+    #[must_use]
+    pub unsafe fn violations(&self, id: usize) -> Vec<Violation> {
+        let gases = Gas::ALL.map(|gas| self.get_gas(id, gas));
+        let volume = self.get_volume(id);
+        let temperature = self.get_temperature(id);
+        let values = gases.iter().copied().chain([volume, temperature]);
+
+        let mut violations = Vec::new();
+        if values.clone().any(Float::is_nan) {
+            violations.push(Violation::Nan);
+        }
+        if values.clone().any(Float::is_infinite) {
+            violations.push(Violation::Infinity);
+        }
+        if gases.iter().any(|&moles| moles < 0.0) {
+            violations.push(Violation::NegativeMoles);
+        }
+        if volume <= 0.0 {
+            violations.push(Violation::NonPositiveVolume);
+        }
+        if temperature < TCMB && gases.iter().sum::<Float>() != 0.0 {
+            violations.push(Violation::TemperatureBelowTcmb);
+        }
+
+        violations
+    }
+
+    /// ### Description
+    /// Scans every registered slot.
+    /// ### Returns
+    /// Ids of the mixtures with violated invariants and the violations of each one.
+    #[must_use]
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    pub unsafe fn check_invariants(&self) -> Vec<(usize, Vec<Violation>)> {
+        profile!("check_invariants");
+
+        (0..self.slots_count())
+            .filter(|&id| self.get_is_initialized(id))
+            .filter_map(|id| {
+                let violations = self.violations(id);
+
+                (!violations.is_empty()).then_some((id, violations))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,56 @@
+use super::{Gas, Mixture};
+use crate::constants::*;
+use crate::profile;
+use crate::utils::Float;
+
+impl Mixture {
+    /// ### Description
+    /// Combines the `ids` mixtures and splits the result between them in proportion to their volumes,
+    /// like merging all of them into one and removing each share back with `remove_ratio`,
+    /// except that the shares aren't quantized, so the moles aren't lost to the rounding of every member.
+    /// The temperature is weighted by the heat capacity, it's kept as is if the combined heat capacity is negligible.
+    /// Unregistered and duplicated ids are skipped.
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    pub unsafe fn equalize_all(&mut self, ids: &[usize]) {
+        profile!("equalize_all");
+
+        let mut ids = ids
+            .iter()
+            .copied()
+            .filter(|&id| self.get_is_initialized(id))
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids.dedup();
+
+        let mut gases: [Float; Gas::ALL.len()] = Default::default();
+        let mut volume = 0.0;
+        let mut heat_capacity = 0.0;
+        let mut thermal_energy = 0.0;
+        for &id in &ids {
+            for (gas, moles) in Gas::ALL.into_iter().zip(gases.iter_mut()) {
+                *moles += self.get_gas(id, gas);
+            }
+
+            let id_heat_capacity = self.heat_capacity(id);
+            volume += self.get_volume(id);
+            heat_capacity += id_heat_capacity;
+            thermal_energy += self.get_temperature(id) * id_heat_capacity;
+        }
+
+        if volume <= Default::default() {
+            return;
+        }
+
+        for id in ids {
+            let ratio = self.get_volume(id) / volume;
+            for (gas, moles) in Gas::ALL.into_iter().zip(gases) {
+                self.set_gas(id, gas, moles * ratio);
+            }
+
+            if heat_capacity > MINIMUM_HEAT_CAPACITY {
+                self.set_temperature(id, thermal_energy / heat_capacity);
+            }
+        }
+    }
+}
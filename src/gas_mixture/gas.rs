@@ -0,0 +1,126 @@
+use super::Mixture;
+use crate::utils::Float;
+
+/// ### Description
+/// Selector of a gas column of [`Mixture`].
+/// Used by the procs that operate on a single gas, that is chosen at the runtime (e.g. from `DM`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gas {
+    Oxygen,
+    CarbonDioxide,
+    Nitrogen,
+    Toxins,
+    Hydrogen,
+    SleepingAgent,
+    AgentB,
+    WaterVapour,
+}
+
+impl Gas {
+    pub const ALL: [Self; 8] = [
+        Self::Oxygen,
+        Self::CarbonDioxide,
+        Self::Nitrogen,
+        Self::Toxins,
+        Self::Hydrogen,
+        Self::SleepingAgent,
+        Self::AgentB,
+        Self::WaterVapour,
+    ];
+
+    /// ### Description
+    /// Name of the gas, the same as the name of the var in `DM`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Oxygen => "oxygen",
+            Self::CarbonDioxide => "carbon_dioxide",
+            Self::Nitrogen => "nitrogen",
+            Self::Toxins => "toxins",
+            Self::Hydrogen => "hydrogen",
+            Self::SleepingAgent => "sleeping_agent",
+            Self::AgentB => "agent_b",
+            Self::WaterVapour => "water_vapour",
+        }
+    }
+
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|gas| gas.name() == name)
+    }
+}
+
+impl Mixture {
+    #[must_use]
+    #[inline(always)]
+    pub unsafe fn get_gas(&self, id: usize, gas: Gas) -> Float {
+        match gas {
+            Gas::Oxygen => self.get_oxygen(id),
+            Gas::CarbonDioxide => self.get_carbon_dioxide(id),
+            Gas::Nitrogen => self.get_nitrogen(id),
+            Gas::Toxins => self.get_toxins(id),
+            Gas::Hydrogen => self.get_hydrogen(id),
+            Gas::SleepingAgent => self.get_sleeping_agent(id),
+            Gas::AgentB => self.get_agent_b(id),
+            Gas::WaterVapour => self.get_water_vapour(id),
+        }
+    }
+
+    #[must_use]
+    #[inline(always)]
+    pub unsafe fn get_gas_archived(&self, id: usize, gas: Gas) -> Float {
+        match gas {
+            Gas::Oxygen => self.get_oxygen_archived(id),
+            Gas::CarbonDioxide => self.get_carbon_dioxide_archived(id),
+            Gas::Nitrogen => self.get_nitrogen_archived(id),
+            Gas::Toxins => self.get_toxins_archived(id),
+            Gas::Hydrogen => self.get_hydrogen_archived(id),
+            Gas::SleepingAgent => self.get_sleeping_agent_archived(id),
+            Gas::AgentB => self.get_agent_b_archived(id),
+            Gas::WaterVapour => self.get_water_vapour_archived(id),
+        }
+    }
+
+    #[inline(always)]
+    pub unsafe fn set_gas(&mut self, id: usize, gas: Gas, value: Float) {
+        match gas {
+            Gas::Oxygen => self.set_oxygen(id, value),
+            Gas::CarbonDioxide => self.set_carbon_dioxide(id, value),
+            Gas::Nitrogen => self.set_nitrogen(id, value),
+            Gas::Toxins => self.set_toxins(id, value),
+            Gas::Hydrogen => self.set_hydrogen(id, value),
+            Gas::SleepingAgent => self.set_sleeping_agent(id, value),
+            Gas::AgentB => self.set_agent_b(id, value),
+            Gas::WaterVapour => self.set_water_vapour(id, value),
+        }
+    }
+
+    #[inline(always)]
+    pub unsafe fn add_gas(&mut self, id: usize, gas: Gas, value: Float) {
+        match gas {
+            Gas::Oxygen => self.add_oxygen(id, value),
+            Gas::CarbonDioxide => self.add_carbon_dioxide(id, value),
+            Gas::Nitrogen => self.add_nitrogen(id, value),
+            Gas::Toxins => self.add_toxins(id, value),
+            Gas::Hydrogen => self.add_hydrogen(id, value),
+            Gas::SleepingAgent => self.add_sleeping_agent(id, value),
+            Gas::AgentB => self.add_agent_b(id, value),
+            Gas::WaterVapour => self.add_water_vapour(id, value),
+        }
+    }
+
+    #[inline(always)]
+    pub unsafe fn sub_gas(&mut self, id: usize, gas: Gas, value: Float) {
+        match gas {
+            Gas::Oxygen => self.sub_oxygen(id, value),
+            Gas::CarbonDioxide => self.sub_carbon_dioxide(id, value),
+            Gas::Nitrogen => self.sub_nitrogen(id, value),
+            Gas::Toxins => self.sub_toxins(id, value),
+            Gas::Hydrogen => self.sub_hydrogen(id, value),
+            Gas::SleepingAgent => self.sub_sleeping_agent(id, value),
+            Gas::AgentB => self.sub_agent_b(id, value),
+            Gas::WaterVapour => self.sub_water_vapour(id, value),
+        }
+    }
+}
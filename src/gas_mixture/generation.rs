@@ -0,0 +1,57 @@
+use super::Mixture;
+use crate::profile;
+
+impl Mixture {
+    /// ### Description
+    /// Generation of the changes made before the first [`Mixture::get_changed_since`].
+    pub(super) const FIRST_GENERATION: u64 = 1;
+
+    /// ### Description
+    /// Stamps the `id` mixture with the current generation and remembers it on the first change of the generation.
+    /// Called by every setter, `add_*` and `sub_*` of the air, so `merge`, `remove`, `share`, `react` and others mark the mixture too.
+    #[inline(always)]
+    pub unsafe fn mark_changed(&mut self, id: usize) {
+        let generation = self.generations.get_unchecked_mut(id);
+        if *generation != self.generation {
+            *generation = self.generation;
+            self.changed.push(id);
+        }
+    }
+
+    /// ### Description
+    /// Generation of the last change of the air of the `id` mixture.
+    #[must_use]
+    #[inline(always)]
+    pub unsafe fn get_generation(&self, id: usize) -> u64 {
+        *self.generations.get_unchecked(id)
+    }
+
+    /// ### Description
+    /// Collects the registered mixtures that have changed after `generation` and starts a new generation,
+    /// so the changes made after this call are newer than the returned generation.
+    /// Passing `0` returns every mixture that has ever changed.
+    /// Passing the generation returned by the previous call only drains the mixtures remembered by [`Mixture::mark_changed`],
+    /// any older generation scans every slot. So one caller should own the calls, otherwise every other caller scans.
+    /// ### Returns
+    /// The ids of the changed mixtures and the generation to pass to the next call.
+    #[must_use]
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    pub unsafe fn get_changed_since(&mut self, generation: u64) -> (Vec<usize>, u64) {
+        profile!("get_changed_since");
+
+        let mut changed = std::mem::take(&mut self.changed);
+        if generation == self.generation - 1 {
+            changed.retain(|&id| self.get_is_initialized(id));
+        } else {
+            changed = (0..self.slots_count())
+                .filter(|&id| self.get_is_initialized(id) && self.get_generation(id) > generation)
+                .collect();
+        }
+
+        let current_generation = self.generation;
+        self.generation += 1;
+
+        (changed, current_generation)
+    }
+}
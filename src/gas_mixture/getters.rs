@@ -1,4 +1,5 @@
 use super::Mixture;
+use crate::utils::Float;
 
 macro_rules! get_methods {
     ($($method:ident, $field:ident);+ $(;)?) => {
@@ -6,7 +7,7 @@ macro_rules! get_methods {
             $(
                 #[inline(always)]
                 #[must_use]
-                pub unsafe fn $method(&self, id: usize) -> f32 {
+                pub unsafe fn $method(&self, id: usize) -> Float {
                     *self.$field.get_unchecked(id)
                 }
             )+
@@ -19,8 +20,10 @@ get_methods! {
     get_carbon_dioxide, carbon_dioxide;
     get_nitrogen, nitrogen;
     get_toxins, toxins;
+    get_hydrogen, hydrogen;
     get_sleeping_agent, sleeping_agent;
     get_agent_b, agent_b;
+    get_water_vapour, water_vapour;
     get_volume, volume;
     get_temperature, temperature;
     get_last_share, last_share;
@@ -29,7 +32,9 @@ get_methods! {
     get_carbon_dioxide_archived, carbon_dioxide_archived;
     get_nitrogen_archived, nitrogen_archived;
     get_toxins_archived, toxins_archived;
+    get_hydrogen_archived, hydrogen_archived;
     get_sleeping_agent_archived, sleeping_agent_archived;
     get_agent_b_archived, agent_b_archived;
+    get_water_vapour_archived, water_vapour_archived;
     get_temperature_archived, temperature_archived;
 }
@@ -0,0 +1,102 @@
+use super::Mixture;
+use crate::config::CONFIG;
+use crate::constants::*;
+use crate::utils::Float;
+
+// Solvers of the ideal gas law for machines. All of them return `0.0` instead of dividing by zero volume
+// or moles, so a pump connected to an empty or broken pipenet doesn't get an infinity.
+impl Mixture {
+    /// ### Description
+    /// Moles to add to the `id` mixture at its temperature to reach `target_pressure` kPa.
+    /// Negative if the mixture is already above it, i.e. moles to remove.
+    /// An empty mixture has no temperature, so the moles are counted at [`TCMB`].
+    #[must_use]
+    #[inline(always)]
+    pub unsafe fn moles_for_pressure(&self, id: usize, target_pressure: Float) -> Float {
+        let volume = self.get_volume(id);
+        if volume <= Default::default() {
+            return Default::default();
+        }
+
+        let temperature = self.get_temperature(id).max(TCMB);
+
+        target_pressure * volume / (R_IDEAL_GAS_EQUATION * temperature) - self.total_moles(id)
+    }
+
+    /// ### Description
+    /// Pressure of the `id` mixture after adding `moles` of gas at `temperature`, e.g. what a pump would reach.
+    /// The composition of the added gas is unknown, so it's assumed to have the molar heat capacity of the mixture
+    /// and the temperatures are weighted by the moles. Use [`Mixture::pressure_after_merging`] for a known mixture.
+    #[must_use]
+    #[inline(always)]
+    pub unsafe fn pressure_after_adding(
+        &self,
+        id: usize,
+        moles: Float,
+        temperature: Float,
+    ) -> Float {
+        let volume = self.get_volume(id);
+        let own_moles = self.total_moles(id);
+        let total_moles = own_moles + moles;
+        if volume <= Default::default() || total_moles <= Default::default() {
+            return Default::default();
+        }
+
+        let mixed_temperature =
+            (own_moles * self.get_temperature(id) + moles * temperature) / total_moles;
+
+        total_moles * R_IDEAL_GAS_EQUATION * mixed_temperature / volume
+    }
+
+    /// ### Description
+    /// Pressure of the `id` mixture after merging the `giver_id` one into it, with the temperature of [`Mixture::merge`].
+    #[must_use]
+    #[inline(always)]
+    pub unsafe fn pressure_after_merging(&self, id: usize, giver_id: usize) -> Float {
+        let volume = self.get_volume(id);
+        let total_moles = self.total_moles(id) + self.total_moles(giver_id);
+        if volume <= Default::default() || total_moles <= Default::default() {
+            return Default::default();
+        }
+
+        total_moles * R_IDEAL_GAS_EQUATION * self.mix_temperature(id, giver_id) / volume
+    }
+
+    /// ### Description
+    /// Temperature the `id` mixture needs to have `target_pressure` kPa with its moles and volume.
+    #[must_use]
+    #[inline(always)]
+    pub unsafe fn temperature_for_pressure(&self, id: usize, target_pressure: Float) -> Float {
+        let total_moles = self.total_moles(id);
+        if total_moles <= Default::default() {
+            return Default::default();
+        }
+
+        target_pressure * self.get_volume(id).max(Default::default())
+            / (R_IDEAL_GAS_EQUATION * total_moles)
+    }
+
+    /// ### Description
+    /// Temperature of the `id` mixture after merging the `other_id` one into it, weighted by the heat capacity.
+    /// It's kept as is if the temperatures differ by less than [`crate::config::Config::minimum_temperature_delta_to_consider`]
+    /// or both mixtures have no heat capacity. Used by [`Mixture::merge`].
+    #[must_use]
+    #[inline(always)]
+    pub unsafe fn mix_temperature(&self, id: usize, other_id: usize) -> Float {
+        let temperature = self.get_temperature(id);
+        let other_temperature = self.get_temperature(other_id);
+        if (temperature - other_temperature).abs() <= CONFIG.minimum_temperature_delta_to_consider {
+            return temperature;
+        }
+
+        let heat_capacity = self.heat_capacity(id);
+        let other_heat_capacity = self.heat_capacity(other_id);
+        let combined_heat_capacity = heat_capacity + other_heat_capacity;
+        if combined_heat_capacity == 0.0 {
+            return temperature;
+        }
+
+        (temperature * heat_capacity + other_temperature * other_heat_capacity)
+            / combined_heat_capacity
+    }
+}
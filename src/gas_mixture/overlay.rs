@@ -0,0 +1,87 @@
+use super::{Gas, Mixture};
+use crate::config::CONFIG;
+use crate::utils::Float;
+use byondapi::value::ByondValue;
+
+impl Gas {
+    /// ### Description
+    /// Moles above which `DM` shows the overlay of the gas on a turf, `None` if the gas has no overlay.
+    #[must_use]
+    #[inline(always)]
+    pub fn visibility_threshold(self) -> Option<Float> {
+        unsafe {
+            match self {
+                Self::Toxins => Some(CONFIG.toxins_visible_moles),
+                Self::SleepingAgent => Some(CONFIG.sleeping_agent_visible_moles),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// ### Description
+/// Set of the gases whose overlays are shown on a turf, bit `n` stands for the `n`th gas of [`Gas::ALL`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Overlays(u8);
+
+impl Overlays {
+    #[must_use]
+    #[inline(always)]
+    pub const fn contains(self, gas: Gas) -> bool {
+        self.0 & (1 << gas as u8) != 0
+    }
+
+    #[inline(always)]
+    fn insert(&mut self, gas: Gas) {
+        self.0 |= 1 << gas as u8;
+    }
+
+    /// ### Description
+    /// Converts the set to a `DM` list of the names of the gases.
+    pub fn to_list(self) -> eyre::Result<ByondValue> {
+        let mut list = ByondValue::new_list()?;
+        for gas in Gas::ALL {
+            if self.contains(gas) {
+                list.push_list(ByondValue::try_from(gas.name())?)?;
+            }
+        }
+
+        Ok(list)
+    }
+}
+
+impl Mixture {
+    /// ### Description
+    /// Overlays that the `id` mixture should show by [`Gas::visibility_threshold`].
+    #[must_use]
+    #[inline(always)]
+    pub unsafe fn visible_overlays(&self, id: usize) -> Overlays {
+        let mut overlays = Overlays::default();
+        for gas in Gas::ALL {
+            if let Some(threshold) = gas.visibility_threshold() {
+                if self.get_gas(id, gas) > threshold {
+                    overlays.insert(gas);
+                }
+            }
+        }
+
+        overlays
+    }
+
+    /// ### Description
+    /// Stores [`Mixture::visible_overlays`] as the current overlays of the `id` mixture.
+    /// ### Returns
+    /// Whether the overlays have changed since the previous update.
+    #[must_use]
+    #[inline(always)]
+    pub unsafe fn update_overlays(&mut self, id: usize) -> bool {
+        let overlays = self.visible_overlays(id);
+        if overlays == self.get_overlays(id) {
+            return false;
+        }
+
+        self.set_overlays(id, overlays);
+
+        true
+    }
+}
@@ -1,55 +1,62 @@
-use super::Mixture;
-use crate::{constants::*, turf::*, utils::*};
+use super::{Gas, Mixture};
 use crate::profile;
+use crate::reaction::{ReactionResult, REACTIONS};
+use crate::{config::CONFIG, constants::*, stats::STATS, turf::*, utils::*};
 use byondapi::value::ByondValue;
 
 impl Mixture {
     #[must_use]
     #[inline(always)]
-    pub unsafe fn heat_capacity(&self, id: usize) -> f32 {
+    pub unsafe fn heat_capacity(&self, id: usize) -> Float {
         crate::utils::calculate_heat_capacity(
             self.get_oxygen(id),
             self.get_carbon_dioxide(id),
             self.get_nitrogen(id),
             self.get_toxins(id),
+            self.get_hydrogen(id),
             self.get_sleeping_agent(id),
             self.get_agent_b(id),
+            self.get_water_vapour(id),
         )
     }
 
     #[must_use]
     #[inline(always)]
-    unsafe fn get_heat_capacity_archived(&self, id: usize) -> f32 {
+    unsafe fn get_heat_capacity_archived(&self, id: usize) -> Float {
         crate::utils::calculate_heat_capacity(
             self.get_oxygen_archived(id),
             self.get_carbon_dioxide_archived(id),
             self.get_nitrogen_archived(id),
             self.get_toxins_archived(id),
+            self.get_hydrogen_archived(id),
             self.get_sleeping_agent_archived(id),
             self.get_agent_b_archived(id),
+            self.get_water_vapour_archived(id),
         )
     }
 
     #[must_use]
     #[inline(always)]
-    pub unsafe fn total_moles(&self, id: usize) -> f32 {
+    pub unsafe fn total_moles(&self, id: usize) -> Float {
         self.get_oxygen(id)
             + self.get_carbon_dioxide(id)
             + self.get_nitrogen(id)
             + self.get_toxins(id)
+            + self.get_hydrogen(id)
             + self.get_sleeping_agent(id)
             + self.get_agent_b(id)
+            + self.get_water_vapour(id)
     }
 
     #[must_use]
     #[inline(always)]
-    pub unsafe fn get_total_trace_moles(&self, id: usize) -> f32 {
+    pub unsafe fn get_total_trace_moles(&self, id: usize) -> Float {
         self.get_sleeping_agent(id) + self.get_agent_b(id)
     }
 
     #[must_use]
     #[inline(always)]
-    pub unsafe fn return_pressure(&self, id: usize) -> f32 {
+    pub unsafe fn return_pressure(&self, id: usize) -> Float {
         let volume = self.get_volume(id);
         if volume > Default::default() {
             return self.total_moles(id) * R_IDEAL_GAS_EQUATION * self.get_temperature(id) / volume;
@@ -62,13 +69,13 @@ impl Mixture {
     // Anyway, it could cause, potentially, unexpected behavior.
     #[must_use]
     #[inline(always)]
-    pub unsafe fn return_volume(&self, id: usize) -> f32 {
+    pub unsafe fn return_volume(&self, id: usize) -> Float {
         self.get_volume(id).max(Default::default())
     }
 
     #[must_use]
     #[inline(always)]
-    pub unsafe fn thermal_energy(&self, id: usize) -> f32 {
+    pub unsafe fn thermal_energy(&self, id: usize) -> Float {
         self.get_temperature(id) * self.heat_capacity(id)
     }
 
@@ -80,98 +87,22 @@ impl Mixture {
 
         let mut reacting = Default::default(); //set to 1 if a notable reaction occured (used by pipe_network)
 
-        let temperature = self.get_temperature(id);
-        let agent_b = self.get_agent_b(id);
-        let toxins = self.get_toxins(id);
-        let carbon_dioxide = self.get_carbon_dioxide(id);
-
-        if agent_b != 0.0
-            && temperature > 900.0
-            && toxins > MINIMUM_HEAT_CAPACITY
-            && carbon_dioxide > MINIMUM_HEAT_CAPACITY
-        {
-            let gases = [carbon_dioxide * 0.75, toxins * 0.25, agent_b * 0.05];
-
-            let reaction_rate = gases
-                .into_iter()
-                .min_by(|a, b| a.total_cmp(b))
-                .unwrap_unchecked();
-
-            self.sub_carbon_dioxide(id, reaction_rate);
-            self.add_oxygen(id, reaction_rate);
-            self.sub_agent_b(id, reaction_rate * 0.05);
-            self.add_temperature(id, reaction_rate * 20_000.0 / self.heat_capacity(id));
-
-            reacting = true;
-        }
-
         self.set_fuel_burnt(id, 0.0);
-        if self.get_temperature(id) > FIRE_MINIMUM_TEMPERATURE_TO_EXIST && self.fire(id) > 0.0 {
-            reacting = true;
-        }
-
-        reacting
-    }
-
-    #[must_use]
-    #[cfg_attr(feature = "profile", inline(never))]
-    #[cfg_attr(not(feature = "profile"), inline(always))]
-    unsafe fn fire(&mut self, id: usize) -> f32 {
-        profile!("fire");
-
-        let mut energy_released = 0.0;
-        let old_heat_capacity = self.heat_capacity(id);
-
-        // Handle plasma burning
-        if self.get_toxins(id) > MINIMUM_HEAT_CAPACITY {
-            let plasma_burn_rate;
-            let oxygen_burn_rate;
-            let temperature = self.get_temperature(id);
-
-            let temperature_scale = if temperature > PLASMA_UPPER_TEMPERATURE {
-                1.0
-            } else {
-                (temperature - PLASMA_MINIMUM_BURN_TEMPERATURE)
-                    / (PLASMA_UPPER_TEMPERATURE - PLASMA_MINIMUM_BURN_TEMPERATURE)
-            };
-
-            if temperature_scale > Default::default() {
-                oxygen_burn_rate = OXYGEN_BURN_RATE_BASE - temperature_scale;
-
-                let toxins = self.get_toxins(id);
-                let oxygen = self.get_oxygen(id);
-
-                if oxygen > toxins * PLASMA_OXYGEN_FULLBURN {
-                    plasma_burn_rate = (toxins * temperature_scale) / PLASMA_BURN_RATE_DELTA;
-                } else {
-                    plasma_burn_rate = (temperature_scale * (oxygen / PLASMA_OXYGEN_FULLBURN))
-                        / PLASMA_BURN_RATE_DELTA;
-                }
-
-                if plasma_burn_rate > MINIMUM_HEAT_CAPACITY {
-                    self.sub_toxins(id, plasma_burn_rate);
-                    self.sub_oxygen(id, plasma_burn_rate * oxygen_burn_rate);
-                    self.add_carbon_dioxide(id, plasma_burn_rate);
-                    self.add_fuel_burnt(id, plasma_burn_rate * (oxygen_burn_rate + 1.0));
-
-                    energy_released += FIRE_PLASMA_ENERGY_RELEASED * plasma_burn_rate;
-                }
+        for reaction in REACTIONS.iter() {
+            if reaction.requirements_met(self, id)
+                && reaction.react(self, id) == ReactionResult::Reacting
+            {
+                reacting = true;
+                STATS.current.reactions += 1;
             }
         }
+        STATS.current.fuel_burnt += self.get_fuel_burnt(id);
 
-        if energy_released > Default::default() {
-            let new_heat_capacity = self.heat_capacity(id);
-
-            if new_heat_capacity > MINIMUM_HEAT_CAPACITY {
-                self.set_temperature(
-                    id,
-                    (self.get_temperature(id) * old_heat_capacity + energy_released)
-                        / new_heat_capacity,
-                );
-            }
+        if CONFIG.sanitization {
+            self.sanitize(id);
         }
 
-        self.get_fuel_burnt(id)
+        reacting
     }
 
     #[inline(always)]
@@ -180,8 +111,10 @@ impl Mixture {
         self.set_carbon_dioxide_archived(id, self.get_carbon_dioxide(id));
         self.set_nitrogen_archived(id, self.get_nitrogen(id));
         self.set_toxins_archived(id, self.get_toxins(id));
+        self.set_hydrogen_archived(id, self.get_hydrogen(id));
         self.set_sleeping_agent_archived(id, self.get_sleeping_agent(id));
         self.set_agent_b_archived(id, self.get_agent_b(id));
+        self.set_water_vapour_archived(id, self.get_water_vapour(id));
         self.set_temperature_archived(id, self.get_temperature(id));
     }
 
@@ -195,35 +128,23 @@ impl Mixture {
             return Default::default();
         }
 
-        if (self.get_temperature(id) - self.get_temperature(giver_id)).abs()
-            > MINIMUM_TEMPERATURE_DELTA_TO_CONSIDER
-        {
-            let self_heat_capacity = self.heat_capacity(id);
-            let giver_heat_capacity = self.heat_capacity(giver_id);
-            let combined_heat_capacity = self_heat_capacity + giver_heat_capacity;
-            if combined_heat_capacity != 0.0 {
-                self.set_temperature(
-                    id,
-                    (self.get_temperature(giver_id) * giver_heat_capacity
-                        + self.get_temperature(id) * self_heat_capacity)
-                        / combined_heat_capacity,
-                );
-            }
-        }
+        self.set_temperature(id, self.mix_temperature(id, giver_id));
 
         self.add_oxygen(id, self.get_oxygen(giver_id));
         self.add_carbon_dioxide(id, self.get_carbon_dioxide(giver_id));
         self.add_nitrogen(id, self.get_nitrogen(giver_id));
         self.add_toxins(id, self.get_toxins(giver_id));
+        self.add_hydrogen(id, self.get_hydrogen(giver_id));
         self.add_sleeping_agent(id, self.get_sleeping_agent(giver_id));
         self.add_agent_b(id, self.get_agent_b(giver_id));
+        self.add_water_vapour(id, self.get_water_vapour(giver_id));
 
         true
     }
 
     #[cfg_attr(feature = "profile", inline(never))]
     #[cfg_attr(not(feature = "profile"), inline(always))]
-    pub unsafe fn remove(&mut self, id: usize, removed_id: usize, mut amount: f32) {
+    pub unsafe fn remove(&mut self, id: usize, removed_id: usize, mut amount: Float) {
         profile!("remove");
 
         let sum = self.total_moles(id);
@@ -239,28 +160,34 @@ impl Mixture {
         let removed_nitrogen_quantized = quantize(self.get_nitrogen(id) / sum * amount);
         let removed_carbon_dioxide_quantized = quantize(self.get_carbon_dioxide(id) / sum * amount);
         let removed_toxins_quantized = quantize(self.get_toxins(id) / sum * amount);
+        let removed_hydrogen_quantized = quantize(self.get_hydrogen(id) / sum * amount);
         let removed_sleeping_agent_quantized = quantize(self.get_sleeping_agent(id) / sum * amount);
         let removed_agent_b_quantized = quantize(self.get_agent_b(id) / sum * amount);
+        let removed_water_vapour_quantized = quantize(self.get_water_vapour(id) / sum * amount);
 
         self.set_oxygen(removed_id, removed_oxygen_quantized);
         self.set_nitrogen(removed_id, removed_nitrogen_quantized);
         self.set_carbon_dioxide(removed_id, removed_carbon_dioxide_quantized);
         self.set_toxins(removed_id, removed_toxins_quantized);
+        self.set_hydrogen(removed_id, removed_hydrogen_quantized);
         self.set_sleeping_agent(removed_id, removed_sleeping_agent_quantized);
         self.set_agent_b(removed_id, removed_agent_b_quantized);
+        self.set_water_vapour(removed_id, removed_water_vapour_quantized);
         self.set_temperature(removed_id, self.get_temperature(id));
 
         self.sub_oxygen(id, removed_oxygen_quantized);
         self.sub_nitrogen(id, removed_nitrogen_quantized);
         self.sub_carbon_dioxide(id, removed_carbon_dioxide_quantized);
         self.sub_toxins(id, removed_toxins_quantized);
+        self.sub_hydrogen(id, removed_hydrogen_quantized);
         self.sub_sleeping_agent(id, removed_sleeping_agent_quantized);
         self.sub_agent_b(id, removed_agent_b_quantized);
+        self.sub_water_vapour(id, removed_water_vapour_quantized);
     }
 
     #[cfg_attr(feature = "profile", inline(never))]
     #[cfg_attr(not(feature = "profile"), inline(always))]
-    pub unsafe fn remove_ratio(&mut self, id: usize, removed_id: usize, mut ratio: f32) {
+    pub unsafe fn remove_ratio(&mut self, id: usize, removed_id: usize, mut ratio: Float) {
         profile!("remove_ratio");
 
         if ratio <= 0.0 {
@@ -275,23 +202,98 @@ impl Mixture {
         let removed_nitrogen_quantized = quantize(self.get_nitrogen(id) * ratio);
         let removed_carbon_dioxide_quantized = quantize(self.get_carbon_dioxide(id) * ratio);
         let removed_toxins_quantized = quantize(self.get_toxins(id) * ratio);
+        let removed_hydrogen_quantized = quantize(self.get_hydrogen(id) * ratio);
         let removed_sleeping_agent_quantized = quantize(self.get_sleeping_agent(id) * ratio);
         let removed_agent_b_quantized = quantize(self.get_agent_b(id) * ratio);
+        let removed_water_vapour_quantized = quantize(self.get_water_vapour(id) * ratio);
 
         self.set_oxygen(removed_id, removed_oxygen_quantized);
         self.set_nitrogen(removed_id, removed_nitrogen_quantized);
         self.set_carbon_dioxide(removed_id, removed_carbon_dioxide_quantized);
         self.set_toxins(removed_id, removed_toxins_quantized);
+        self.set_hydrogen(removed_id, removed_hydrogen_quantized);
         self.set_sleeping_agent(removed_id, removed_sleeping_agent_quantized);
         self.set_agent_b(removed_id, removed_agent_b_quantized);
+        self.set_water_vapour(removed_id, removed_water_vapour_quantized);
         self.set_temperature(removed_id, self.get_temperature(id));
 
         self.sub_oxygen(id, removed_oxygen_quantized);
         self.sub_nitrogen(id, removed_nitrogen_quantized);
         self.sub_carbon_dioxide(id, removed_carbon_dioxide_quantized);
         self.sub_toxins(id, removed_toxins_quantized);
+        self.sub_hydrogen(id, removed_hydrogen_quantized);
         self.sub_sleeping_agent(id, removed_sleeping_agent_quantized);
         self.sub_agent_b(id, removed_agent_b_quantized);
+        self.sub_water_vapour(id, removed_water_vapour_quantized);
+    }
+
+    /// ### Description
+    /// Moves `amount` moles of only the `gas` into the `removed_id` mixture, that gets the temperature of the `id` one.
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    pub unsafe fn remove_specific(
+        &mut self,
+        id: usize,
+        removed_id: usize,
+        gas: Gas,
+        mut amount: Float,
+    ) {
+        profile!("remove_specific");
+
+        amount = amount.min(self.get_gas(id, gas));
+
+        if amount <= Default::default() {
+            self.unregister(removed_id);
+
+            return;
+        }
+
+        self.move_specific(id, removed_id, gas, quantize(amount));
+    }
+
+    /// ### Description
+    /// Moves `ratio` of only the `gas` into the `removed_id` mixture, that gets the temperature of the `id` one.
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    pub unsafe fn remove_specific_ratio(
+        &mut self,
+        id: usize,
+        removed_id: usize,
+        gas: Gas,
+        mut ratio: Float,
+    ) {
+        profile!("remove_specific_ratio");
+
+        if ratio <= 0.0 {
+            self.unregister(removed_id);
+
+            return;
+        }
+
+        ratio = ratio.min(1.0);
+
+        self.move_specific(id, removed_id, gas, quantize(self.get_gas(id, gas) * ratio));
+    }
+
+    /// ### Description
+    /// Rounding up by `quantize` may exceed the moles of the `gas`, so the moved moles are clamped to them.
+    #[inline(always)]
+    unsafe fn move_specific(
+        &mut self,
+        id: usize,
+        removed_id: usize,
+        gas: Gas,
+        removed_quantized: Float,
+    ) {
+        let removed_quantized = removed_quantized.min(self.get_gas(id, gas));
+
+        for removed_gas in Gas::ALL {
+            self.set_gas(removed_id, removed_gas, Default::default());
+        }
+        self.set_gas(removed_id, gas, removed_quantized);
+        self.set_temperature(removed_id, self.get_temperature(id));
+
+        self.sub_gas(id, gas, removed_quantized);
     }
 
     #[inline(always)]
@@ -300,8 +302,10 @@ impl Mixture {
         self.set_carbon_dioxide(id, self.get_carbon_dioxide(sample_id));
         self.set_nitrogen(id, self.get_nitrogen(sample_id));
         self.set_toxins(id, self.get_toxins(sample_id));
+        self.set_hydrogen(id, self.get_hydrogen(sample_id));
         self.set_sleeping_agent(id, self.get_sleeping_agent(sample_id));
         self.set_agent_b(id, self.get_agent_b(sample_id));
+        self.set_water_vapour(id, self.get_water_vapour(sample_id));
         self.set_temperature(id, self.get_temperature(sample_id));
     }
 
@@ -312,7 +316,7 @@ impl Mixture {
         &self,
         id: usize,
         turf_model: Turf,
-        mut atmos_adjacent_turfs: f32,
+        mut atmos_adjacent_turfs: Float,
     ) -> bool {
         profile!("check_turf");
 
@@ -320,8 +324,10 @@ impl Mixture {
         let carbon_dioxide_archived = self.get_carbon_dioxide_archived(id);
         let nitrogen_archived = self.get_nitrogen_archived(id);
         let toxins_archived = self.get_toxins_archived(id);
+        let hydrogen_archived = self.get_hydrogen_archived(id);
         let sleeping_agent_archived = self.get_sleeping_agent_archived(id);
         let agent_b_archived = self.get_agent_b_archived(id);
+        let water_vapour_archived = self.get_water_vapour_archived(id);
 
         atmos_adjacent_turfs += 1.0;
         let delta_oxygen = (oxygen_archived - turf_model.oxygen) / atmos_adjacent_turfs;
@@ -329,18 +335,23 @@ impl Mixture {
             (carbon_dioxide_archived - turf_model.carbon_dioxide) / atmos_adjacent_turfs;
         let delta_nitrogen = (nitrogen_archived - turf_model.nitrogen) / atmos_adjacent_turfs;
         let delta_toxins = (toxins_archived - turf_model.toxins) / atmos_adjacent_turfs;
+        let delta_hydrogen = (hydrogen_archived - turf_model.hydrogen) / atmos_adjacent_turfs;
         let delta_sleeping_agent =
             (sleeping_agent_archived - turf_model.sleeping_agent) / atmos_adjacent_turfs;
         let delta_agent_b = (agent_b_archived - turf_model.agent_b) / atmos_adjacent_turfs;
+        let delta_water_vapour =
+            (water_vapour_archived - turf_model.water_vapour) / atmos_adjacent_turfs;
         let delta_temperature = self.get_temperature_archived(id) - turf_model.temperature;
 
         if Self::check_turf_condition(delta_oxygen.abs(), oxygen_archived)
             || Self::check_turf_condition(delta_carbon_dioxide.abs(), carbon_dioxide_archived)
             || Self::check_turf_condition(delta_nitrogen.abs(), nitrogen_archived)
             || Self::check_turf_condition(delta_toxins.abs(), toxins_archived)
+            || Self::check_turf_condition(delta_hydrogen.abs(), hydrogen_archived)
             || Self::check_turf_condition(delta_sleeping_agent.abs(), sleeping_agent_archived)
             || Self::check_turf_condition(delta_agent_b.abs(), agent_b_archived)
-            || delta_temperature.abs() > MINIMUM_TEMPERATURE_DELTA_TO_SUSPEND
+            || Self::check_turf_condition(delta_water_vapour.abs(), water_vapour_archived)
+            || delta_temperature.abs() > CONFIG.minimum_temperature_delta_to_suspend
         {
             return false;
         }
@@ -358,24 +369,30 @@ impl Mixture {
         let carbon_dioxide = self.get_carbon_dioxide(id);
         let nitrogen = self.get_nitrogen(id);
         let toxins = self.get_toxins(id);
+        let hydrogen = self.get_hydrogen(id);
         let sleeping_agent = self.get_sleeping_agent(id);
         let agent_b = self.get_agent_b(id);
+        let water_vapour = self.get_water_vapour(id);
 
         let delta_oxygen = oxygen - turf_model.oxygen;
         let delta_carbon_dioxide = carbon_dioxide - turf_model.carbon_dioxide;
         let delta_nitrogen = nitrogen - turf_model.nitrogen;
         let delta_toxins = toxins - turf_model.toxins;
+        let delta_hydrogen = hydrogen - turf_model.hydrogen;
         let delta_sleeping_agent = sleeping_agent - turf_model.sleeping_agent;
         let delta_agent_b = agent_b - turf_model.agent_b;
+        let delta_water_vapour = water_vapour - turf_model.water_vapour;
         let delta_temperature = self.get_temperature(id) - turf_model.temperature;
 
         if Self::check_turf_condition(delta_oxygen.abs(), oxygen)
             || Self::check_turf_condition(delta_carbon_dioxide.abs(), carbon_dioxide)
             || Self::check_turf_condition(delta_nitrogen.abs(), nitrogen)
             || Self::check_turf_condition(delta_toxins.abs(), toxins)
+            || Self::check_turf_condition(delta_hydrogen.abs(), hydrogen)
             || Self::check_turf_condition(delta_sleeping_agent.abs(), sleeping_agent)
             || Self::check_turf_condition(delta_agent_b.abs(), agent_b)
-            || delta_temperature.abs() > MINIMUM_TEMPERATURE_DELTA_TO_SUSPEND
+            || Self::check_turf_condition(delta_water_vapour.abs(), water_vapour)
+            || delta_temperature.abs() > CONFIG.minimum_temperature_delta_to_suspend
         {
             return false;
         }
@@ -390,10 +407,12 @@ impl Mixture {
         &mut self,
         id: usize,
         sharer_id: usize,
-        mut atmos_adjacent_turfs: f32,
-    ) -> f32 {
+        mut atmos_adjacent_turfs: Float,
+    ) -> Float {
         profile!("share");
 
+        STATS.current.share_calls += 1;
+
         if !self.get_is_initialized(sharer_id) {
             return Default::default();
         }
@@ -402,24 +421,30 @@ impl Mixture {
         let carbon_dioxide_archived = self.get_carbon_dioxide_archived(id);
         let nitrogen_archived = self.get_nitrogen_archived(id);
         let toxins_archived = self.get_toxins_archived(id);
+        let hydrogen_archived = self.get_hydrogen_archived(id);
         let sleeping_agent_archived = self.get_sleeping_agent_archived(id);
         let agent_b_archived = self.get_agent_b_archived(id);
+        let water_vapour_archived = self.get_water_vapour_archived(id);
         let temperature_archived = self.get_temperature_archived(id);
 
         let sharer_oxygen_archived = self.get_oxygen_archived(sharer_id);
         let sharer_carbon_dioxide_archived = self.get_carbon_dioxide_archived(sharer_id);
         let sharer_nitrogen_archived = self.get_nitrogen_archived(sharer_id);
         let sharer_toxins_archived = self.get_toxins_archived(sharer_id);
+        let sharer_hydrogen_archived = self.get_hydrogen_archived(sharer_id);
         let sharer_sleeping_agent_archived = self.get_sleeping_agent_archived(sharer_id);
         let sharer_agent_b_archived = self.get_agent_b_archived(sharer_id);
+        let sharer_water_vapour_archived = self.get_water_vapour_archived(sharer_id);
         let sharer_temperature_archived = self.get_temperature_archived(sharer_id);
 
         if oxygen_archived == sharer_oxygen_archived
             && carbon_dioxide_archived == sharer_carbon_dioxide_archived
             && nitrogen_archived == sharer_nitrogen_archived
             && toxins_archived == sharer_toxins_archived
+            && hydrogen_archived == sharer_hydrogen_archived
             && sleeping_agent_archived == sharer_sleeping_agent_archived
             && agent_b_archived == sharer_agent_b_archived
+            && water_vapour_archived == sharer_water_vapour_archived
             && temperature_archived == sharer_temperature_archived
         {
             return Default::default();
@@ -435,11 +460,15 @@ impl Mixture {
             quantize(nitrogen_archived - sharer_nitrogen_archived) / atmos_adjacent_turfs;
         let delta_toxins =
             quantize(toxins_archived - sharer_toxins_archived) / atmos_adjacent_turfs;
+        let delta_hydrogen =
+            quantize(hydrogen_archived - sharer_hydrogen_archived) / atmos_adjacent_turfs;
         let delta_sleeping_agent =
             quantize(sleeping_agent_archived - sharer_sleeping_agent_archived)
                 / atmos_adjacent_turfs;
         let delta_agent_b =
             quantize(agent_b_archived - sharer_agent_b_archived) / atmos_adjacent_turfs;
+        let delta_water_vapour =
+            quantize(water_vapour_archived - sharer_water_vapour_archived) / atmos_adjacent_turfs;
         let delta_temperature = temperature_archived - sharer_temperature_archived;
 
         let mut old_self_heat_capacity = 0.0;
@@ -448,7 +477,7 @@ impl Mixture {
         let mut heat_capacity_self_to_sharer = 0.0;
         let mut heat_capacity_sharer_to_self = 0.0;
 
-        if delta_temperature.abs() > MINIMUM_TEMPERATURE_DELTA_TO_CONSIDER {
+        if delta_temperature.abs() > CONFIG.minimum_temperature_delta_to_consider {
             let delta_air = delta_oxygen + delta_nitrogen;
             if delta_air != Default::default() {
                 let air_heat_capacity = SPECIFIC_HEAT_AIR * delta_air;
@@ -477,6 +506,15 @@ impl Mixture {
                 }
             }
 
+            if delta_hydrogen != Default::default() {
+                let hydrogen_heat_capacity = SPECIFIC_HEAT_HYDROGEN * delta_hydrogen;
+                if delta_hydrogen > Default::default() {
+                    heat_capacity_self_to_sharer += hydrogen_heat_capacity;
+                } else {
+                    heat_capacity_sharer_to_self -= hydrogen_heat_capacity;
+                }
+            }
+
             if delta_sleeping_agent != Default::default() {
                 let sleeping_agent_heat_capacity = SPECIFIC_HEAT_N2O * delta_sleeping_agent;
                 if delta_sleeping_agent > Default::default() {
@@ -495,6 +533,15 @@ impl Mixture {
                 }
             }
 
+            if delta_water_vapour != Default::default() {
+                let water_vapour_heat_capacity = SPECIFIC_HEAT_WATER_VAPOUR * delta_water_vapour;
+                if delta_water_vapour > Default::default() {
+                    heat_capacity_self_to_sharer += water_vapour_heat_capacity;
+                } else {
+                    heat_capacity_sharer_to_self -= water_vapour_heat_capacity;
+                }
+            }
+
             old_self_heat_capacity = self.heat_capacity(id);
             old_sharer_heat_capacity = self.heat_capacity(sharer_id);
         }
@@ -511,18 +558,26 @@ impl Mixture {
         self.sub_toxins(id, delta_toxins);
         self.add_toxins(sharer_id, delta_toxins);
 
+        self.sub_hydrogen(id, delta_hydrogen);
+        self.add_hydrogen(sharer_id, delta_hydrogen);
+
         self.sub_sleeping_agent(id, delta_sleeping_agent);
         self.add_sleeping_agent(sharer_id, delta_sleeping_agent);
 
         self.sub_agent_b(id, delta_agent_b);
         self.add_agent_b(sharer_id, delta_agent_b);
 
+        self.sub_water_vapour(id, delta_water_vapour);
+        self.add_water_vapour(sharer_id, delta_water_vapour);
+
         let moved_moles = delta_oxygen
             + delta_carbon_dioxide
             + delta_nitrogen
             + delta_toxins
+            + delta_hydrogen
             + delta_sleeping_agent
-            + delta_agent_b;
+            + delta_agent_b
+            + delta_water_vapour;
 
         self.set_last_share(
             id,
@@ -530,11 +585,14 @@ impl Mixture {
                 + delta_carbon_dioxide.abs()
                 + delta_nitrogen.abs()
                 + delta_toxins.abs()
+                + delta_hydrogen.abs()
                 + delta_sleeping_agent.abs()
-                + delta_agent_b.abs(),
+                + delta_agent_b.abs()
+                + delta_water_vapour.abs(),
         );
+        STATS.current.moles_moved += self.get_last_share(id);
 
-        if delta_temperature.abs() > MINIMUM_TEMPERATURE_DELTA_TO_CONSIDER {
+        if delta_temperature.abs() > CONFIG.minimum_temperature_delta_to_consider {
             let new_self_heat_capacity = old_self_heat_capacity + heat_capacity_sharer_to_self
                 - heat_capacity_self_to_sharer;
             let new_sharer_heat_capacity = old_sharer_heat_capacity + heat_capacity_self_to_sharer
@@ -568,14 +626,14 @@ impl Mixture {
                         sharer_id,
                         temperature_archived,
                         sharer_temperature_archived,
-                        OPEN_HEAT_TRANSFER_COEFFICIENT,
+                        CONFIG.open_heat_transfer_coefficient,
                     );
                 }
             }
         }
 
-        if delta_temperature > MINIMUM_TEMPERATURE_TO_MOVE
-            || moved_moles.abs() > MINIMUM_MOLES_DELTA_TO_MOVE
+        let delta_pressure = if delta_temperature > CONFIG.minimum_temperature_to_move
+            || moved_moles.abs() > CONFIG.minimum_moles_delta_to_move()
         {
             let delta_pressure = temperature_archived * (self.total_moles(id) + moved_moles)
                 - sharer_temperature_archived * (self.total_moles(sharer_id) - moved_moles);
@@ -583,7 +641,14 @@ impl Mixture {
             delta_pressure * R_IDEAL_GAS_EQUATION / self.get_volume(id)
         } else {
             Default::default()
+        };
+
+        if CONFIG.sanitization {
+            self.sanitize(id);
+            self.sanitize(sharer_id);
         }
+
+        delta_pressure
     }
 
     #[cfg_attr(feature = "profile", inline(never))]
@@ -592,15 +657,15 @@ impl Mixture {
         &mut self,
         id: usize,
         sharer_id: usize,
-        temperature_archived: f32,
-        sharer_temperature_archived: f32,
-        conduction_coefficient: f32,
+        temperature_archived: Float,
+        sharer_temperature_archived: Float,
+        conduction_coefficient: Float,
     ) {
         profile!("temperature_share");
 
         let delta_temperature = temperature_archived - sharer_temperature_archived;
 
-        if delta_temperature.abs() > MINIMUM_TEMPERATURE_DELTA_TO_CONSIDER {
+        if delta_temperature.abs() > CONFIG.minimum_temperature_delta_to_consider {
             let self_heat_capacity = self.get_heat_capacity_archived(id);
             let sharer_heat_capacity = self.get_heat_capacity_archived(sharer_id);
 
@@ -625,10 +690,10 @@ impl Mixture {
         &mut self,
         id: usize,
         turf_model: Turf,
-        model_thermal_conductivity: f32,
-        model_heat_capacity: f32,
-        mut atmos_adjacent_turfs: f32,
-    ) -> f32 {
+        model_thermal_conductivity: Float,
+        model_heat_capacity: Float,
+        mut atmos_adjacent_turfs: Float,
+    ) -> Float {
         profile!("mimic");
 
         let temperature_archived = self.get_temperature_archived(id);
@@ -643,17 +708,22 @@ impl Mixture {
             quantize(self.get_nitrogen_archived(id) - turf_model.nitrogen) / atmos_adjacent_turfs;
         let delta_toxins =
             quantize(self.get_toxins_archived(id) - turf_model.toxins) / atmos_adjacent_turfs;
+        let delta_hydrogen =
+            quantize(self.get_hydrogen_archived(id) - turf_model.hydrogen) / atmos_adjacent_turfs;
         let delta_sleeping_agent =
             quantize(self.get_sleeping_agent_archived(id) - turf_model.sleeping_agent)
                 / atmos_adjacent_turfs;
         let delta_agent_b =
             quantize(self.get_agent_b_archived(id) - turf_model.agent_b) / atmos_adjacent_turfs;
+        let delta_water_vapour =
+            quantize(self.get_water_vapour_archived(id) - turf_model.water_vapour)
+                / atmos_adjacent_turfs;
         let delta_temperature = temperature_archived - turf_model.temperature;
 
         let mut old_self_heat_capacity = Default::default();
-        let mut heat_capacity_transferred: f32 = Default::default();
+        let mut heat_capacity_transferred: Float = Default::default();
 
-        if delta_temperature.abs() > MINIMUM_TEMPERATURE_DELTA_TO_CONSIDER {
+        if delta_temperature.abs() > CONFIG.minimum_temperature_delta_to_consider {
             let delta_air = delta_oxygen + delta_nitrogen;
             if delta_air != Default::default() {
                 let air_heat_capacity = SPECIFIC_HEAT_AIR * delta_air;
@@ -670,6 +740,11 @@ impl Mixture {
                 heat_capacity_transferred -= toxins_heat_capacity;
             }
 
+            if delta_hydrogen != Default::default() {
+                let hydrogen_heat_capacity = SPECIFIC_HEAT_HYDROGEN * delta_hydrogen;
+                heat_capacity_transferred -= hydrogen_heat_capacity;
+            }
+
             if delta_sleeping_agent != Default::default() {
                 let sleeping_agent_heat_capacity = SPECIFIC_HEAT_N2O * delta_sleeping_agent;
                 heat_capacity_transferred -= sleeping_agent_heat_capacity;
@@ -680,6 +755,11 @@ impl Mixture {
                 heat_capacity_transferred -= agent_b_heat_capacity;
             }
 
+            if delta_water_vapour != Default::default() {
+                let water_vapour_heat_capacity = SPECIFIC_HEAT_WATER_VAPOUR * delta_water_vapour;
+                heat_capacity_transferred -= water_vapour_heat_capacity;
+            }
+
             old_self_heat_capacity = self.heat_capacity(id);
         }
 
@@ -687,15 +767,19 @@ impl Mixture {
         self.sub_carbon_dioxide(id, delta_carbon_dioxide);
         self.sub_nitrogen(id, delta_nitrogen);
         self.sub_toxins(id, delta_toxins);
+        self.sub_hydrogen(id, delta_hydrogen);
         self.sub_sleeping_agent(id, delta_sleeping_agent);
         self.sub_agent_b(id, delta_agent_b);
+        self.sub_water_vapour(id, delta_water_vapour);
 
         let moved_moles = delta_oxygen
             + delta_carbon_dioxide
             + delta_nitrogen
             + delta_toxins
+            + delta_hydrogen
             + delta_sleeping_agent
-            + delta_agent_b;
+            + delta_agent_b
+            + delta_water_vapour;
 
         self.set_last_share(
             id,
@@ -703,11 +787,14 @@ impl Mixture {
                 + delta_carbon_dioxide.abs()
                 + delta_nitrogen.abs()
                 + delta_toxins.abs()
+                + delta_hydrogen.abs()
                 + delta_sleeping_agent.abs()
-                + delta_agent_b.abs(),
+                + delta_agent_b.abs()
+                + delta_water_vapour.abs(),
         );
+        STATS.current.moles_moved += self.get_last_share(id);
 
-        if delta_temperature.abs() > MINIMUM_TEMPERATURE_DELTA_TO_CONSIDER {
+        if delta_temperature.abs() > CONFIG.minimum_temperature_delta_to_consider {
             let new_self_heat_capacity = old_self_heat_capacity - heat_capacity_transferred;
             if new_self_heat_capacity > MINIMUM_HEAT_CAPACITY {
                 self.set_temperature(
@@ -726,8 +813,8 @@ impl Mixture {
             );
         }
 
-        if (delta_temperature > MINIMUM_TEMPERATURE_TO_MOVE)
-            || moved_moles.abs() > MINIMUM_MOLES_DELTA_TO_MOVE
+        let delta_pressure = if (delta_temperature > CONFIG.minimum_temperature_to_move)
+            || moved_moles.abs() > CONFIG.minimum_moles_delta_to_move()
         {
             let delta_pressure = temperature_archived * (self.total_moles(id) + moved_moles)
                 - turf_model.temperature
@@ -735,13 +822,21 @@ impl Mixture {
                         + turf_model.carbon_dioxide
                         + turf_model.nitrogen
                         + turf_model.toxins
+                        + turf_model.hydrogen
                         + turf_model.sleeping_agent
-                        + turf_model.agent_b);
+                        + turf_model.agent_b
+                        + turf_model.water_vapour);
 
             delta_pressure * R_IDEAL_GAS_EQUATION / self.get_volume(id)
         } else {
             Default::default()
+        };
+
+        if CONFIG.sanitization {
+            self.sanitize(id);
         }
+
+        delta_pressure
     }
 
     #[cfg_attr(feature = "profile", inline(never))]
@@ -749,16 +844,16 @@ impl Mixture {
     pub unsafe fn temperature_mimic(
         &mut self,
         id: usize,
-        model_temperature: f32,
-        model_heat_capacity: f32,
-        conduction_coefficient: f32,
+        model_temperature: Float,
+        model_heat_capacity: Float,
+        conduction_coefficient: Float,
     ) {
         profile!("temperature_mimic");
 
         let temperature = self.get_temperature(id);
         let delta_temperature = temperature - model_temperature;
 
-        if delta_temperature.abs() > MINIMUM_TEMPERATURE_DELTA_TO_CONSIDER {
+        if delta_temperature.abs() > CONFIG.minimum_temperature_delta_to_consider {
             let self_heat_capacity = self.heat_capacity(id);
 
             if model_heat_capacity > MINIMUM_HEAT_CAPACITY
@@ -774,33 +869,64 @@ impl Mixture {
         }
     }
 
+    /// ### Description
+    /// [`Mixture::mimic`] against space, without a need to read a space turf from `DM`.
+    /// Drains the gas like any other open turf and radiates heat with [`HEAT_CAPACITY_VACUUM`].
+    #[must_use]
+    #[inline(always)]
+    pub unsafe fn mimic_space(&mut self, id: usize, atmos_adjacent_turfs: Float) -> Float {
+        self.mimic(
+            id,
+            Turf::SPACE,
+            CONFIG.open_heat_transfer_coefficient,
+            HEAT_CAPACITY_VACUUM,
+            atmos_adjacent_turfs,
+        )
+    }
+
+    /// ### Description
+    /// [`Mixture::temperature_mimic`] against space.
+    #[inline(always)]
+    pub unsafe fn temperature_mimic_space(&mut self, id: usize, conduction_coefficient: Float) {
+        self.temperature_mimic(
+            id,
+            Turf::SPACE.temperature,
+            HEAT_CAPACITY_VACUUM,
+            conduction_coefficient,
+        );
+    }
+
     #[cfg_attr(feature = "profile", inline(never))]
     #[cfg_attr(not(feature = "profile"), inline(always))]
     pub unsafe fn temperature_turf_share(
         &mut self,
         id: usize,
         turf_sharer: &mut ByondValue,
-        conduction_coefficient: f32,
+        conduction_coefficient: Float,
     ) {
         profile!("temperature_turf_share");
 
         const TEMPERATURE_NAME: &str = "temperature";
 
         // TODO: Make the setters and getters methods for the turfs.
-        let turf_sharer_temperature = turf_sharer
-            .read_var(TEMPERATURE_NAME)
-            .unwrap_unchecked()
-            .get_number()
-            .unwrap_unchecked();
+        let turf_sharer_temperature = from_number(
+            turf_sharer
+                .read_var(TEMPERATURE_NAME)
+                .unwrap_unchecked()
+                .get_number()
+                .unwrap_unchecked(),
+        );
 
         let delta_temperature = self.get_temperature_archived(id) - turf_sharer_temperature;
 
-        if delta_temperature.abs() > MINIMUM_TEMPERATURE_DELTA_TO_CONSIDER {
-            let turf_sharer_heat_capacity = turf_sharer
-                .read_var("heat_capacity")
-                .unwrap_unchecked()
-                .get_number()
-                .unwrap_unchecked();
+        if delta_temperature.abs() > CONFIG.minimum_temperature_delta_to_consider {
+            let turf_sharer_heat_capacity = from_number(
+                turf_sharer
+                    .read_var("heat_capacity")
+                    .unwrap_unchecked()
+                    .get_number()
+                    .unwrap_unchecked(),
+            );
             let self_heat_capacity = self.heat_capacity(id);
 
             if (turf_sharer_heat_capacity > MINIMUM_HEAT_CAPACITY)
@@ -815,9 +941,9 @@ impl Mixture {
                 turf_sharer
                     .write_var(
                         TEMPERATURE_NAME,
-                        &ByondValue::from(
+                        &ByondValue::from(to_number(
                             turf_sharer_temperature + heat / turf_sharer_heat_capacity,
-                        ),
+                        )),
                     )
                     .unwrap_unchecked();
             }
@@ -854,6 +980,12 @@ impl Mixture {
             return Default::default();
         }
 
+        let hydrogen = self.get_hydrogen(id);
+        let sample_hydrogen = self.get_hydrogen(sample_id);
+        if Self::compare_condition(hydrogen, sample_hydrogen) {
+            return Default::default();
+        }
+
         let sleeping_agent = self.get_sleeping_agent(id);
         let sample_sleeping_agent = self.get_sleeping_agent(sample_id);
         if Self::compare_condition(sleeping_agent, sample_sleeping_agent) {
@@ -866,9 +998,15 @@ impl Mixture {
             return Default::default();
         }
 
+        let water_vapour = self.get_water_vapour(id);
+        let sample_water_vapour = self.get_water_vapour(sample_id);
+        if Self::compare_condition(water_vapour, sample_water_vapour) {
+            return Default::default();
+        }
+
         let temperature = self.get_temperature(id);
         let sample_temperature = self.get_temperature(sample_id);
-        if self.total_moles(id) > MINIMUM_AIR_TO_SUSPEND
+        if self.total_moles(id) > CONFIG.minimum_air_to_suspend()
             && Self::compare_condition(temperature, sample_temperature)
         {
             return Default::default();
@@ -879,14 +1017,14 @@ impl Mixture {
 
     #[must_use]
     #[inline(always)]
-    pub unsafe fn get_breath_partial_pressure(&self, id: usize, gas_pressure: f32) -> f32 {
+    pub unsafe fn get_breath_partial_pressure(&self, id: usize, gas_pressure: Float) -> Float {
         (gas_pressure * R_IDEAL_GAS_EQUATION * self.get_temperature(id)) / BREATH_VOLUME
     }
 
     // Reverse of the above
     #[must_use]
     #[inline(always)]
-    pub unsafe fn get_true_breath_pressure(&self, id: usize, breath_pp: f32) -> f32 {
+    pub unsafe fn get_true_breath_pressure(&self, id: usize, breath_pp: Float) -> Float {
         (breath_pp * BREATH_VOLUME) / (R_IDEAL_GAS_EQUATION * self.get_temperature(id))
     }
 }
@@ -0,0 +1,36 @@
+use super::{Gas, Mixture};
+use crate::constants::*;
+use crate::stats::STATS;
+
+impl Mixture {
+    /// ### Description
+    /// `share` and `mimic` subtract unclamped deltas and `fire` divides by the heat capacity,
+    /// so float errors can leave slightly negative moles or a `NaN` temperature, that then spread over the station.
+    /// Sets negative, `NaN` and infinite moles to zero and a `NaN` or infinite temperature to [`TCMB`].
+    /// Moles above `-SANITIZATION_EPSILON` are counted in [`crate::stats::TickStats::sanitized_values`],
+    /// the lower ones are a bug rather than a float error and are counted in [`crate::stats::TickStats::large_negative_moles`].
+    #[inline(always)]
+    pub unsafe fn sanitize(&mut self, id: usize) {
+        let mut sanitized_values = 0;
+        let mut large_negative_moles = 0;
+
+        for gas in Gas::ALL {
+            let moles = self.get_gas(id, gas);
+            if moles < -SANITIZATION_EPSILON {
+                self.set_gas(id, gas, Default::default());
+                large_negative_moles += 1;
+            } else if !moles.is_finite() || moles < 0.0 {
+                self.set_gas(id, gas, Default::default());
+                sanitized_values += 1;
+            }
+        }
+
+        if !self.get_temperature(id).is_finite() {
+            self.set_temperature(id, TCMB);
+            sanitized_values += 1;
+        }
+
+        STATS.current.sanitized_values += sanitized_values;
+        STATS.current.large_negative_moles += large_negative_moles;
+    }
+}
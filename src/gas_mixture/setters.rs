@@ -1,11 +1,31 @@
 use super::Mixture;
+use crate::utils::Float;
 
+// Setters of the air, they mark the mixture as changed, see `Mixture::mark_changed`.
 macro_rules! set_methods {
     ($($method:ident, $field:ident);+ $(;)?) => {
         impl Mixture {
             $(
                 #[inline(always)]
-                pub unsafe fn $method(&mut self, id: usize, value: f32) {
+                pub unsafe fn $method(&mut self, id: usize, value: Float) {
+                    let field = self.$field.get_unchecked_mut(id);
+                    if *field != value {
+                        *field = value;
+                        self.mark_changed(id);
+                    }
+                }
+            )+
+        }
+    }
+}
+
+// Setters of the bookkeeping columns, that aren't visible to the players.
+macro_rules! set_untracked_methods {
+    ($($method:ident, $field:ident);+ $(;)?) => {
+        impl Mixture {
+            $(
+                #[inline(always)]
+                pub unsafe fn $method(&mut self, id: usize, value: Float) {
                     *self.$field.get_unchecked_mut(id) = value;
                 }
             )+
@@ -18,17 +38,24 @@ set_methods! {
     set_carbon_dioxide, carbon_dioxide;
     set_nitrogen, nitrogen;
     set_toxins, toxins;
+    set_hydrogen, hydrogen;
     set_sleeping_agent, sleeping_agent;
     set_agent_b, agent_b;
+    set_water_vapour, water_vapour;
     set_volume, volume;
     set_temperature, temperature;
+}
+
+set_untracked_methods! {
     set_last_share, last_share;
     set_fuel_burnt, fuel_burnt;
     set_oxygen_archived, oxygen_archived;
     set_carbon_dioxide_archived, carbon_dioxide_archived;
     set_nitrogen_archived, nitrogen_archived;
     set_toxins_archived, toxins_archived;
+    set_hydrogen_archived, hydrogen_archived;
     set_sleeping_agent_archived, sleeping_agent_archived;
     set_agent_b_archived, agent_b_archived;
+    set_water_vapour_archived, water_vapour_archived;
     set_temperature_archived, temperature_archived;
 }
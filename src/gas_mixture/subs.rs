@@ -1,12 +1,16 @@
 use super::Mixture;
+use crate::utils::Float;
 
 macro_rules! sub_methods {
     ($($method:ident, $field:ident);+ $(;)?) => {
         impl Mixture {
             $(
                 #[inline(always)]
-                pub unsafe fn $method(&mut self, id: usize, value: f32) {
-                    *self.$field.get_unchecked_mut(id) -= value;
+                pub unsafe fn $method(&mut self, id: usize, value: Float) {
+                    if value != Default::default() {
+                        *self.$field.get_unchecked_mut(id) -= value;
+                        self.mark_changed(id);
+                    }
                 }
             )+
         }
@@ -18,7 +22,9 @@ sub_methods! {
     sub_carbon_dioxide, carbon_dioxide;
     sub_nitrogen, nitrogen;
     sub_toxins, toxins;
+    sub_hydrogen, hydrogen;
     sub_sleeping_agent, sleeping_agent;
     sub_agent_b, agent_b;
+    sub_water_vapour, water_vapour;
     sub_temperature, temperature;
 }
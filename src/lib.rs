@@ -1,7 +1,14 @@
 #![feature(const_fn_floating_point_arithmetic)]
 
+mod adjacency;
+mod alarm;
+mod config;
 mod constants;
-mod gas_mixture;
+pub mod gas_mixture;
 mod procs;
+mod reaction;
+mod reservoir;
+mod species;
+mod stats;
 mod turf;
 mod utils;
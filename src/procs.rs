@@ -1 +1,13 @@
+mod adjacency;
+mod alarm;
+mod config;
+mod debug;
 mod gas_mixture;
+mod generation;
+mod profiling;
+mod reservoir;
+mod stats;
+
+use crate::utils::Float;
+
+const DEFAULT_ATMOS_ADJACENT_TURFS: Float = 4.0;
@@ -0,0 +1,57 @@
+use byondapi::value::ByondValue;
+
+use crate::adjacency::{Neighbour, TurfNode, ADJACENCY};
+use crate::gas_mixture::MIXTURES;
+use crate::reservoir::RESERVOIRS;
+use crate::{id, mixture_id, null, profile_proc};
+
+/// `neighbours` is `list(north, south, east, west)`, see [`Neighbour::new`] for the values.
+#[byondapi::bind]
+pub fn set_turf_adjacency(src: ByondValue, turf: ByondValue, neighbours: ByondValue) {
+    profile_proc!("set_turf_adjacency");
+
+    let neighbours = neighbours.get_list_values()?;
+    let [north, south, east, west] = neighbours.as_slice() else {
+        eyre::bail!("Expected 4 neighbours, got {}", neighbours.len());
+    };
+
+    let node = TurfNode {
+        turf,
+        neighbours: [north, south, east, west].map(|neighbour| Neighbour::new(*neighbour)),
+    };
+    for neighbour in node.neighbours {
+        if let Neighbour::Mixture(id) = neighbour {
+            if !unsafe { MIXTURES.is_allocated(id) } {
+                eyre::bail!("Neighbour gas_mixture {id} was never registered");
+            }
+        }
+    }
+
+    unsafe { ADJACENCY.set(id!(src), node) };
+
+    null!()
+}
+
+#[byondapi::bind]
+pub fn remove_turf_adjacency(src: ByondValue) {
+    profile_proc!("remove_turf_adjacency");
+
+    unsafe { ADJACENCY.remove(id!(src)) };
+
+    null!()
+}
+
+/// Returns `list("pressure_movements" = list(list(turf, direction, force), ...), "wet_turfs" = list(turf, ...),
+/// "overlay_changes" = list(list(turf, list(gas_name, ...)), ...))`.
+#[byondapi::bind]
+pub fn process_turfs(active: ByondValue) {
+    profile_proc!("process_turfs");
+
+    let active = active
+        .get_list_values()?
+        .iter()
+        .map(|mixture| mixture_id!(mixture))
+        .collect::<eyre::Result<Vec<_>>>()?;
+
+    unsafe { ADJACENCY.process(&mut MIXTURES, &RESERVOIRS, &active) }.to_list()
+}
@@ -0,0 +1,51 @@
+use byondapi::value::ByondValue;
+
+use crate::alarm::{AlarmProfile, ALARM_PROFILES};
+use crate::gas_mixture::MIXTURES;
+use crate::{id, mixture_id, null, profile_proc};
+
+#[byondapi::bind]
+pub fn set_alarm_profile(profile: ByondValue, thresholds: ByondValue) {
+    profile_proc!("set_alarm_profile");
+
+    let alarm_profile = AlarmProfile::new(thresholds)?;
+
+    unsafe { ALARM_PROFILES.insert(id!(profile), alarm_profile) };
+
+    null!()
+}
+
+#[byondapi::bind]
+pub fn remove_alarm_profile(profile: ByondValue) {
+    profile_proc!("remove_alarm_profile");
+
+    unsafe { ALARM_PROFILES.remove(&id!(profile)) };
+
+    null!()
+}
+
+/// Returns `list(level, metric)`, where `metric` is `null` if nothing tripped.
+#[byondapi::bind]
+pub fn evaluate_alarms(mixtures: ByondValue, profile: ByondValue) {
+    profile_proc!("evaluate_alarms");
+
+    let Some(alarm_profile) = (unsafe { ALARM_PROFILES.get(&id!(profile)) }) else {
+        eyre::bail!("Alarm profile isn't registered");
+    };
+
+    let ids = mixtures
+        .get_list_values()?
+        .iter()
+        .map(|mixture| mixture_id!(mixture))
+        .collect::<eyre::Result<Vec<_>>>()?;
+    let (level, metric) = unsafe { alarm_profile.evaluate(&*MIXTURES, ids) };
+
+    let metric = match metric {
+        Some(metric) => ByondValue::new_str(metric.name())?,
+        None => ByondValue::null(),
+    };
+
+    Ok([ByondValue::from(level as u8 as f32), metric]
+        .as_slice()
+        .try_into()?)
+}
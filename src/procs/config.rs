@@ -0,0 +1,40 @@
+use byondapi::value::ByondValue;
+
+use crate::config::{Config, CONFIG};
+use crate::{null, profile_proc};
+
+/// `config` is an associative list of `entry name = value`, entries that aren't in the list keep their values.
+/// Nothing is changed if any entry is invalid, see [`Config::validate`].
+#[byondapi::bind]
+pub fn set_atmos_config(config: ByondValue) {
+    profile_proc!("set_atmos_config");
+
+    let mut new_config = unsafe { CONFIG };
+    for (name, value) in config.iter()? {
+        new_config.set(&name.get_string()?, value.get_number()?)?;
+    }
+    new_config.validate()?;
+
+    unsafe { CONFIG = new_config };
+
+    null!()
+}
+
+#[byondapi::bind]
+pub fn load_atmos_config(path: ByondValue) {
+    profile_proc!("load_atmos_config");
+
+    let new_config = Config::from_file(&path.get_string()?)?;
+    new_config.validate()?;
+
+    unsafe { CONFIG = new_config };
+
+    null!()
+}
+
+#[byondapi::bind]
+pub fn get_atmos_config() {
+    profile_proc!("get_atmos_config");
+
+    unsafe { CONFIG }.to_list()
+}
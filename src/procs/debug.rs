@@ -0,0 +1,46 @@
+use byondapi::value::ByondValue;
+
+use crate::gas_mixture::MIXTURES;
+use crate::{id, profile_proc};
+
+/// `mixture` is a `/datum/gas_mixture` or the number of its slot.
+/// Returns every column of the slot, including archived values.
+#[byondapi::bind]
+pub fn atmos_debug_dump(mixture: ByondValue) {
+    profile_proc!("atmos_debug_dump");
+
+    let id = match mixture.get_number() {
+        Ok(id) => id as usize,
+        Err(_) => id!(mixture),
+    };
+    if id >= unsafe { MIXTURES.slots_count() } {
+        eyre::bail!("Mixture id {id} is out of {} slots", unsafe {
+            MIXTURES.slots_count()
+        });
+    }
+    if !unsafe { MIXTURES.is_allocated(id) } {
+        eyre::bail!("Mixture id {id} is in a page that was never registered");
+    }
+
+    unsafe { MIXTURES.dump(id) }.to_list()
+}
+
+/// Scans every registered mixture for `NaN`, infinity, negative moles, non-positive volume and temperature below `TCMB`.
+/// Returns a list of `list(id, list(violation names))`, empty if everything is fine.
+#[byondapi::bind]
+pub fn atmos_check_invariants() {
+    profile_proc!("atmos_check_invariants");
+
+    let mut report = ByondValue::new_list()?;
+    for (id, violations) in unsafe { MIXTURES.check_invariants() } {
+        let mut names = ByondValue::new_list()?;
+        for violation in violations {
+            names.push_list(ByondValue::new_str(violation.name())?)?;
+        }
+
+        let entry: ByondValue = [ByondValue::from(id as f32), names].as_slice().try_into()?;
+        report.push_list(entry)?;
+    }
+
+    Ok(report)
+}
@@ -1,25 +1,12 @@
 use byondapi::byond_string;
 use byondapi::value::ByondValue;
 
-use crate::gas_mixture::MIXTURES;
+use super::DEFAULT_ATMOS_ADJACENT_TURFS;
+use crate::gas_mixture::{Gas, MIXTURES};
+use crate::species::SpeciesProfile;
 use crate::turf::Turf;
-use crate::{id, null, profile_proc, value};
-
-const DEFAULT_ATMOS_ADJACENT_TURFS: f32 = 4.0;
-
-#[cfg(any(feature = "profile", feature = "profile_proc"))]
-#[hook("/proc/enable_tracy")]
-pub fn enable_tracy() {
-    use tracing::Level;
-    use tracing_subscriber::layer::SubscriberExt;
-
-    tracing::subscriber::set_global_default(
-        tracing_subscriber::registry()
-            .with(tracing_subscriber::EnvFilter::default().add_directive(Level::DEBUG.into()))
-            .with(tracing_tracy::TracyLayer::new()),
-    )
-    .expect("set up the subscriber");
-}
+use crate::utils::from_number;
+use crate::{id, mixture_id, null, profile_proc, value};
 
 #[byondapi::bind("/datum/gas_mixture/proc/")]
 pub fn register(id: ByondValue) {
@@ -41,7 +28,7 @@ pub fn unregister(id: ByondValue) {
 
 #[byondapi::bind]
 pub fn get_is_initialized(src: ByondValue) {
-    profile_proc!("unregister");
+    profile_proc!("get_is_initialized");
 
     value!(unsafe { MIXTURES.get_is_initialized(id!(src)) })
 }
@@ -50,70 +37,103 @@ pub fn get_is_initialized(src: ByondValue) {
 pub fn get_oxygen(src: ByondValue) {
     profile_proc!("get_oxygen");
 
-    value!(unsafe { MIXTURES.get_oxygen(id!(src)) })
+    value!(unsafe { MIXTURES.get_oxygen(mixture_id!(src)?) })
 }
 
 #[byondapi::bind]
 pub fn get_carbon_dioxide(src: ByondValue) {
     profile_proc!("get_carbon_dioxide");
 
-    value!(unsafe { MIXTURES.get_carbon_dioxide(id!(src)) })
+    value!(unsafe { MIXTURES.get_carbon_dioxide(mixture_id!(src)?) })
 }
 
 #[byondapi::bind]
 pub fn get_nitrogen(src: ByondValue) {
     profile_proc!("get_nitrogen");
 
-    value!(unsafe { MIXTURES.get_nitrogen(id!(src)) })
+    value!(unsafe { MIXTURES.get_nitrogen(mixture_id!(src)?) })
 }
 
 #[byondapi::bind]
 pub fn get_toxins(src: ByondValue) {
     profile_proc!("get_toxins");
 
-    value!(unsafe { MIXTURES.get_toxins(id!(src)) })
+    value!(unsafe { MIXTURES.get_toxins(mixture_id!(src)?) })
+}
+
+#[byondapi::bind]
+pub fn get_hydrogen(src: ByondValue) {
+    profile_proc!("get_hydrogen");
+
+    value!(unsafe { MIXTURES.get_hydrogen(mixture_id!(src)?) })
 }
 
 #[byondapi::bind]
 pub fn get_sleeping_agent(src: ByondValue) {
     profile_proc!("get_sleeping_agent");
 
-    value!(unsafe { MIXTURES.get_sleeping_agent(id!(src)) })
+    value!(unsafe { MIXTURES.get_sleeping_agent(mixture_id!(src)?) })
 }
 
 #[byondapi::bind]
 pub fn get_agent_b(src: ByondValue) {
     profile_proc!("get_agent_b");
 
-    value!(unsafe { MIXTURES.get_agent_b(id!(src)) })
+    value!(unsafe { MIXTURES.get_agent_b(mixture_id!(src)?) })
+}
+
+#[byondapi::bind]
+pub fn get_water_vapour(src: ByondValue) {
+    profile_proc!("get_water_vapour");
+
+    value!(unsafe { MIXTURES.get_water_vapour(mixture_id!(src)?) })
 }
 
 #[byondapi::bind]
 pub fn get_volume(src: ByondValue) {
     profile_proc!("get_volume");
 
-    value!(unsafe { MIXTURES.get_volume(id!(src)) })
+    value!(unsafe { MIXTURES.get_volume(mixture_id!(src)?) })
 }
 
 #[byondapi::bind]
 pub fn get_temperature(src: ByondValue) {
     profile_proc!("get_temperature");
 
-    value!(unsafe { MIXTURES.get_temperature(id!(src)) })
+    value!(unsafe { MIXTURES.get_temperature(mixture_id!(src)?) })
 }
 
 #[byondapi::bind]
 pub fn get_last_share(src: ByondValue) {
     profile_proc!("get_last_share");
 
-    value!(unsafe { MIXTURES.get_last_share(id!(src)) })
+    value!(unsafe { MIXTURES.get_last_share(mixture_id!(src)?) })
+}
+
+/// Updates and returns a list of the names of the gases whose overlays are shown.
+/// Call it after changing a mixture outside of `process_turfs`, as only the processed turfs and their neighbours are updated there.
+#[byondapi::bind]
+pub fn get_overlays(src: ByondValue) {
+    profile_proc!("get_overlays");
+
+    unsafe {
+        let id = mixture_id!(src)?;
+        let _ = MIXTURES.update_overlays(id);
+
+        MIXTURES.get_overlays(id).to_list()
+    }
 }
 
 #[byondapi::bind]
 pub fn set_oxygen(src: ByondValue) {
     profile_proc!("set_oxygen");
 
-    unsafe { MIXTURES.set_oxygen(id!(src), src.get_number().unwrap_unchecked()) }
+    unsafe {
+        MIXTURES.set_oxygen(
+            mixture_id!(src)?,
+            from_number(src.get_number().unwrap_unchecked()),
+        )
+    }
 
     null!()
 }
@@ -122,7 +142,12 @@ pub fn set_oxygen(src: ByondValue) {
 pub fn set_carbon_dioxide(src: ByondValue) {
     profile_proc!("set_carbon_dioxide");
 
-    unsafe { MIXTURES.set_carbon_dioxide(id!(src), src.get_number().unwrap_unchecked()) }
+    unsafe {
+        MIXTURES.set_carbon_dioxide(
+            mixture_id!(src)?,
+            from_number(src.get_number().unwrap_unchecked()),
+        )
+    }
 
     null!()
 }
@@ -131,7 +156,12 @@ pub fn set_carbon_dioxide(src: ByondValue) {
 pub fn set_nitrogen(src: ByondValue) {
     profile_proc!("set_nitrogen");
 
-    unsafe { MIXTURES.set_nitrogen(id!(src), src.get_number().unwrap_unchecked()) }
+    unsafe {
+        MIXTURES.set_nitrogen(
+            mixture_id!(src)?,
+            from_number(src.get_number().unwrap_unchecked()),
+        )
+    }
 
     null!()
 }
@@ -140,7 +170,26 @@ pub fn set_nitrogen(src: ByondValue) {
 pub fn set_toxins(src: ByondValue) {
     profile_proc!("set_toxins");
 
-    unsafe { MIXTURES.set_toxins(id!(src), src.get_number().unwrap_unchecked()) }
+    unsafe {
+        MIXTURES.set_toxins(
+            mixture_id!(src)?,
+            from_number(src.get_number().unwrap_unchecked()),
+        )
+    }
+
+    null!()
+}
+
+#[byondapi::bind]
+pub fn set_hydrogen(src: ByondValue) {
+    profile_proc!("set_hydrogen");
+
+    unsafe {
+        MIXTURES.set_hydrogen(
+            mixture_id!(src)?,
+            from_number(src.get_number().unwrap_unchecked()),
+        )
+    }
 
     null!()
 }
@@ -149,7 +198,12 @@ pub fn set_toxins(src: ByondValue) {
 pub fn set_sleeping_agent(src: ByondValue) {
     profile_proc!("set_sleeping_agent");
 
-    unsafe { MIXTURES.set_sleeping_agent(id!(src), src.get_number().unwrap_unchecked()) }
+    unsafe {
+        MIXTURES.set_sleeping_agent(
+            mixture_id!(src)?,
+            from_number(src.get_number().unwrap_unchecked()),
+        )
+    }
 
     null!()
 }
@@ -158,7 +212,26 @@ pub fn set_sleeping_agent(src: ByondValue) {
 pub fn set_agent_b(src: ByondValue) {
     profile_proc!("set_agent_b");
 
-    unsafe { MIXTURES.set_agent_b(id!(src), src.get_number().unwrap_unchecked()) }
+    unsafe {
+        MIXTURES.set_agent_b(
+            mixture_id!(src)?,
+            from_number(src.get_number().unwrap_unchecked()),
+        )
+    }
+
+    null!()
+}
+
+#[byondapi::bind]
+pub fn set_water_vapour(src: ByondValue) {
+    profile_proc!("set_water_vapour");
+
+    unsafe {
+        MIXTURES.set_water_vapour(
+            mixture_id!(src)?,
+            from_number(src.get_number().unwrap_unchecked()),
+        )
+    }
 
     null!()
 }
@@ -167,7 +240,12 @@ pub fn set_agent_b(src: ByondValue) {
 pub fn set_volume(src: ByondValue) {
     profile_proc!("set_volume");
 
-    unsafe { MIXTURES.set_volume(id!(src), src.get_number().unwrap_unchecked()) }
+    unsafe {
+        MIXTURES.set_volume(
+            mixture_id!(src)?,
+            from_number(src.get_number().unwrap_unchecked()),
+        )
+    }
 
     null!()
 }
@@ -176,7 +254,12 @@ pub fn set_volume(src: ByondValue) {
 pub fn set_temperature(src: ByondValue) {
     profile_proc!("set_temperature");
 
-    unsafe { MIXTURES.set_temperature(id!(src), src.get_number().unwrap_unchecked()) }
+    unsafe {
+        MIXTURES.set_temperature(
+            mixture_id!(src)?,
+            from_number(src.get_number().unwrap_unchecked()),
+        )
+    }
 
     null!()
 }
@@ -185,7 +268,12 @@ pub fn set_temperature(src: ByondValue) {
 pub fn set_last_share(src: ByondValue) {
     profile_proc!("set_last_share");
 
-    unsafe { MIXTURES.set_last_share(id!(src), src.get_number().unwrap_unchecked()) }
+    unsafe {
+        MIXTURES.set_last_share(
+            mixture_id!(src)?,
+            from_number(src.get_number().unwrap_unchecked()),
+        )
+    }
 
     null!()
 }
@@ -194,28 +282,28 @@ pub fn set_last_share(src: ByondValue) {
 pub fn get_heat_capacity(src: ByondValue) {
     profile_proc!("get_heat_capacity");
 
-    value!(unsafe { MIXTURES.heat_capacity(id!(src)) })
+    value!(unsafe { MIXTURES.heat_capacity(mixture_id!(src)?) })
 }
 
 #[byondapi::bind]
 pub fn get_total_moles(src: ByondValue) {
     profile_proc!("get_total_moles");
 
-    value!(unsafe { MIXTURES.total_moles(id!(src)) })
+    value!(unsafe { MIXTURES.total_moles(mixture_id!(src)?) })
 }
 
 #[byondapi::bind]
 pub fn get_total_trace_moles(src: ByondValue) {
     profile_proc!("get_total_trace_moles");
 
-    value!(unsafe { MIXTURES.get_total_trace_moles(id!(src)) })
+    value!(unsafe { MIXTURES.get_total_trace_moles(mixture_id!(src)?) })
 }
 
 #[byondapi::bind]
 pub fn get_pressure(src: ByondValue) {
     profile_proc!("get_pressure");
 
-    value!(unsafe { MIXTURES.return_pressure(id!(src)) })
+    value!(unsafe { MIXTURES.return_pressure(mixture_id!(src)?) })
 }
 
 // I'm not sure that this thing was made by a person with good mental health in DM.
@@ -224,28 +312,111 @@ pub fn get_pressure(src: ByondValue) {
 pub fn return_volume(src: ByondValue) {
     profile_proc!("return_volume");
 
-    value!(unsafe { MIXTURES.return_volume(id!(src)) })
+    value!(unsafe { MIXTURES.return_volume(mixture_id!(src)?) })
 }
 
 #[byondapi::bind]
 pub fn get_thermal_energy(src: ByondValue) {
     profile_proc!("get_thermal_energy");
 
-    value!(unsafe { MIXTURES.thermal_energy(id!(src)) })
+    value!(unsafe { MIXTURES.thermal_energy(mixture_id!(src)?) })
+}
+
+#[byondapi::bind]
+pub fn moles_for_pressure(src: ByondValue, target_pressure: ByondValue) {
+    profile_proc!("moles_for_pressure");
+
+    value!(unsafe {
+        MIXTURES.moles_for_pressure(
+            mixture_id!(src)?,
+            from_number(target_pressure.get_number()?),
+        )
+    })
+}
+
+#[byondapi::bind]
+pub fn pressure_after_adding(src: ByondValue, moles: ByondValue, temperature: ByondValue) {
+    profile_proc!("pressure_after_adding");
+
+    value!(unsafe {
+        MIXTURES.pressure_after_adding(
+            mixture_id!(src)?,
+            from_number(moles.get_number()?),
+            from_number(temperature.get_number()?),
+        )
+    })
+}
+
+#[byondapi::bind]
+pub fn pressure_after_merging(src: ByondValue, giver: ByondValue) {
+    profile_proc!("pressure_after_merging");
+
+    value!(unsafe { MIXTURES.pressure_after_merging(mixture_id!(src)?, mixture_id!(giver)?) })
+}
+
+#[byondapi::bind]
+pub fn temperature_for_pressure(src: ByondValue, target_pressure: ByondValue) {
+    profile_proc!("temperature_for_pressure");
+
+    value!(unsafe {
+        MIXTURES.temperature_for_pressure(
+            mixture_id!(src)?,
+            from_number(target_pressure.get_number()?),
+        )
+    })
+}
+
+#[byondapi::bind]
+pub fn mix_temperature(src: ByondValue, other: ByondValue) {
+    profile_proc!("mix_temperature");
+
+    value!(unsafe { MIXTURES.mix_temperature(mixture_id!(src)?, mixture_id!(other)?) })
 }
 
 #[byondapi::bind]
 pub fn react(src: ByondValue) {
     profile_proc!("react");
 
-    value!(unsafe { MIXTURES.react(id!(src)) })
+    value!(unsafe { MIXTURES.react(mixture_id!(src)?) })
 }
 
 #[byondapi::bind]
 pub fn archive(src: ByondValue) {
     profile_proc!("archive");
 
-    unsafe { MIXTURES.archive(id!(src)) }
+    unsafe { MIXTURES.archive(mixture_id!(src)?) }
+
+    null!()
+}
+
+/// Archives a list of mixtures at once, e.g. all the active turfs before a tick.
+#[byondapi::bind]
+pub fn archive_many(mixtures: ByondValue) {
+    profile_proc!("archive_many");
+
+    let mut ids = mixtures
+        .get_list_values()?
+        .iter()
+        .map(|mixture| mixture_id!(mixture))
+        .collect::<eyre::Result<Vec<_>>>()?;
+
+    unsafe { MIXTURES.archive_many(&mut ids) }
+
+    null!()
+}
+
+/// Combines a list of mixtures (a pipenet, connected canisters and so on) and splits the air by their volumes.
+#[byondapi::bind]
+pub fn equalize_all(mixtures: ByondValue) {
+    profile_proc!("equalize_all");
+
+    let ids = mixtures
+        .get_list_values()?
+        .iter()
+        .map(|mixture| mixture_id!(mixture))
+        .collect::<eyre::Result<Vec<_>>>()?;
+
+    unsafe { MIXTURES.equalize_all(&ids) }
 
     null!()
 }
@@ -254,7 +425,7 @@ pub fn archive(src: ByondValue) {
 pub fn merge(src: ByondValue, giver: ByondValue) {
     profile_proc!("merge");
 
-    value!(unsafe { MIXTURES.merge(id!(src), id!(giver)) })
+    value!(unsafe { MIXTURES.merge(mixture_id!(src)?, mixture_id!(giver)?) })
 }
 
 #[byondapi::bind]
@@ -263,9 +434,9 @@ pub fn remove(src: ByondValue, removed: ByondValue, amount: ByondValue) {
 
     unsafe {
         MIXTURES.remove(
-            id!(src),
-            id!(removed),
-            amount.get_number().unwrap_unchecked(),
+            mixture_id!(src)?,
+            mixture_id!(removed)?,
+            from_number(amount.get_number().unwrap_unchecked()),
         );
     }
 
@@ -277,7 +448,113 @@ pub fn remove_ratio(src: ByondValue, removed: ByondValue, ratio: ByondValue) {
     profile_proc!("remove_ratio");
 
     unsafe {
-        MIXTURES.remove_ratio(id!(src), id!(removed), ratio.get_number().unwrap_unchecked());
+        MIXTURES.remove_ratio(
+            mixture_id!(src)?,
+            mixture_id!(removed)?,
+            from_number(ratio.get_number().unwrap_unchecked()),
+        );
+    }
+
+    null!()
+}
+
+/// `gas` is the name of the gas, e.g. `"carbon_dioxide"`.
+#[byondapi::bind]
+pub fn remove_specific(src: ByondValue, removed: ByondValue, gas: ByondValue, amount: ByondValue) {
+    profile_proc!("remove_specific");
+
+    let gas = gas.get_string()?;
+    let Some(gas) = Gas::from_name(&gas) else {
+        eyre::bail!("Unknown gas: {gas}");
+    };
+
+    unsafe {
+        MIXTURES.remove_specific(
+            mixture_id!(src)?,
+            mixture_id!(removed)?,
+            gas,
+            from_number(amount.get_number()?),
+        )
+    }
+
+    null!()
+}
+
+/// `gas` is the name of the gas, e.g. `"carbon_dioxide"`.
+#[byondapi::bind]
+pub fn remove_specific_ratio(
+    src: ByondValue,
+    removed: ByondValue,
+    gas: ByondValue,
+    ratio: ByondValue,
+) {
+    profile_proc!("remove_specific_ratio");
+
+    let gas = gas.get_string()?;
+    let Some(gas) = Gas::from_name(&gas) else {
+        eyre::bail!("Unknown gas: {gas}");
+    };
+
+    unsafe {
+        MIXTURES.remove_specific_ratio(
+            mixture_id!(src)?,
+            mixture_id!(removed)?,
+            gas,
+            from_number(ratio.get_number()?),
+        )
+    }
+
+    null!()
+}
+
+#[byondapi::bind]
+pub fn scale(src: ByondValue, factor: ByondValue) {
+    profile_proc!("scale");
+
+    unsafe { MIXTURES.scale(mixture_id!(src)?, from_number(factor.get_number()?)) }
+
+    null!()
+}
+
+/// Maximum number of parts of `split`, every part is a new datum.
+const MAX_SPLIT_PARTS: usize = 1000;
+
+/// Moves all the air of `src` into `count` new mixtures of the same type in equal parts, returns the list of them.
+#[byondapi::bind]
+pub fn split(src: ByondValue, count: ByondValue) {
+    profile_proc!("split");
+
+    let id = mixture_id!(src)?;
+    let count = count.get_number()?;
+    if !(1.0..=MAX_SPLIT_PARTS as f32).contains(&count) || count.fract() != 0.0 {
+        eyre::bail!("Can't split a mixture into {count} parts, expected 1 to {MAX_SPLIT_PARTS}");
+    }
+    let count = count as usize;
+
+    let mixture_type = src.read_var("type")?;
+    let parts = (0..count)
+        .map(|_| ByondValue::builtin_new(mixture_type, &[]))
+        .collect::<Result<Vec<_>, _>>()?;
+    let ids = parts
+        .iter()
+        .map(|part| mixture_id!(part))
+        .collect::<eyre::Result<Vec<_>>>()?;
+
+    unsafe { MIXTURES.split(id, &ids) }
+
+    Ok(parts.as_slice().try_into()?)
+}
+
+#[byondapi::bind]
+pub fn remove_volume(src: ByondValue, removed: ByondValue, liters: ByondValue) {
+    profile_proc!("remove_volume");
+
+    unsafe {
+        MIXTURES.remove_volume(
+            mixture_id!(src)?,
+            mixture_id!(removed)?,
+            from_number(liters.get_number()?),
+        )
     }
 
     null!()
@@ -288,7 +565,7 @@ pub fn copy_from(src: ByondValue, sample: ByondValue) {
     profile_proc!("copy_from");
 
     unsafe {
-        MIXTURES.copy_from(id!(src), id!(sample));
+        MIXTURES.copy_from(mixture_id!(src)?, mixture_id!(sample)?);
     }
 
     null!()
@@ -301,10 +578,11 @@ pub fn check_turf(src: ByondValue, turf_model: ByondValue, atmos_adjacent_turfs:
     let turf_model = unsafe { Turf::new(turf_model) };
     let atmos_adjacent_turfs = atmos_adjacent_turfs
         .get_number()
+        .map(from_number)
         .unwrap_or(DEFAULT_ATMOS_ADJACENT_TURFS);
 
     value!(unsafe {
-        MIXTURES.check_turf(id!(src), turf_model, atmos_adjacent_turfs)
+        MIXTURES.check_turf(mixture_id!(src)?, turf_model, atmos_adjacent_turfs)
     })
 }
 
@@ -314,7 +592,7 @@ pub fn check_turf_total(src: ByondValue,turf_model: ByondValue) {
 
     let turf_model = unsafe { Turf::new(turf_model) };
 
-    value!(unsafe { MIXTURES.check_turf_total(id!(src), turf_model) })
+    value!(unsafe { MIXTURES.check_turf_total(mixture_id!(src)?, turf_model) })
 }
 
 #[byondapi::bind]
@@ -323,24 +601,32 @@ pub fn share(src: ByondValue, sharer: ByondValue, atmos_adjacent_turfs: ByondVal
 
     let atmos_adjacent_turfs = atmos_adjacent_turfs
         .get_number()
+        .map(from_number)
         .unwrap_or(DEFAULT_ATMOS_ADJACENT_TURFS);
 
-    value!(unsafe { MIXTURES.share(id!(src), id!(sharer), atmos_adjacent_turfs) })
+    value!(unsafe {
+        MIXTURES.share(
+            mixture_id!(src)?,
+            mixture_id!(sharer)?,
+            atmos_adjacent_turfs,
+        )
+    })
 }
 
 #[byondapi::bind]
 pub fn temperature_share(src: ByondValue, sharer: ByondValue, conduction_coefficient: ByondValue) {
     profile_proc!("temperature_share");
 
-    let conduction_coefficient = unsafe { conduction_coefficient.get_number().unwrap_unchecked() };
+    let conduction_coefficient =
+        unsafe { from_number(conduction_coefficient.get_number().unwrap_unchecked()) };
 
     unsafe {
-        let id = id!(src);
-        let sharer_id = id!(sharer);
+        let id = mixture_id!(src)?;
+        let sharer_id = mixture_id!(sharer)?;
 
         MIXTURES.temperature_share(
             id,
-            id!(sharer),
+            mixture_id!(sharer)?,
             MIXTURES.get_temperature_archived(id),
             MIXTURES.get_temperature_archived(sharer_id),
             conduction_coefficient,
@@ -362,15 +648,17 @@ pub fn mimic(
 
     let turf_model = unsafe { Turf::new(turf_model) };
     let model_thermal_conductivity =
-        unsafe { model_thermal_conductivity.get_number().unwrap_unchecked() };
-    let model_heat_capacity = unsafe { model_heat_capacity.get_number().unwrap_unchecked() };
+        unsafe { from_number(model_thermal_conductivity.get_number().unwrap_unchecked()) };
+    let model_heat_capacity =
+        unsafe { from_number(model_heat_capacity.get_number().unwrap_unchecked()) };
     let atmos_adjacent_turfs = atmos_adjacent_turfs
         .get_number()
+        .map(from_number)
         .unwrap_or(DEFAULT_ATMOS_ADJACENT_TURFS);
 
     value!(unsafe {
         MIXTURES.mimic(
-            id!(src),
+            mixture_id!(src)?,
             turf_model,
             model_thermal_conductivity,
             model_heat_capacity,
@@ -388,13 +676,16 @@ pub fn temperature_mimic(
 ) {
     profile_proc!("temperature_mimic");
 
-    let model_temperature = unsafe { model_temperature.get_number().unwrap_unchecked() };
-    let model_heat_capacity = unsafe { model_heat_capacity.get_number().unwrap_unchecked() };
-    let conduction_coefficient = unsafe { conduction_coefficient.get_number().unwrap_unchecked() };
+    let model_temperature =
+        unsafe { from_number(model_temperature.get_number().unwrap_unchecked()) };
+    let model_heat_capacity =
+        unsafe { from_number(model_heat_capacity.get_number().unwrap_unchecked()) };
+    let conduction_coefficient =
+        unsafe { from_number(conduction_coefficient.get_number().unwrap_unchecked()) };
 
     unsafe {
         MIXTURES.temperature_mimic(
-            id!(src),
+            mixture_id!(src)?,
             model_temperature,
             model_heat_capacity,
             conduction_coefficient,
@@ -404,14 +695,45 @@ pub fn temperature_mimic(
     null!()
 }
 
+#[byondapi::bind]
+pub fn mimic_space(src: ByondValue, atmos_adjacent_turfs: ByondValue) {
+    profile_proc!("mimic_space");
+
+    let atmos_adjacent_turfs = atmos_adjacent_turfs
+        .get_number()
+        .map(from_number)
+        .unwrap_or(DEFAULT_ATMOS_ADJACENT_TURFS);
+
+    value!(unsafe { MIXTURES.mimic_space(mixture_id!(src)?, atmos_adjacent_turfs) })
+}
+
+#[byondapi::bind]
+pub fn temperature_mimic_space(src: ByondValue, conduction_coefficient: ByondValue) {
+    profile_proc!("temperature_mimic_space");
+
+    let conduction_coefficient =
+        unsafe { from_number(conduction_coefficient.get_number().unwrap_unchecked()) };
+
+    unsafe {
+        MIXTURES.temperature_mimic_space(mixture_id!(src)?, conduction_coefficient);
+    }
+
+    null!()
+}
+
 #[byondapi::bind]
 pub fn temperature_turf_share(src: ByondValue, mut turf_sharer: ByondValue, conduction_coefficient: ByondValue) {
     profile_proc!("temperature_turf_share");
 
-    let conduction_coefficient = unsafe { conduction_coefficient.get_number().unwrap_unchecked() };
+    let conduction_coefficient =
+        unsafe { from_number(conduction_coefficient.get_number().unwrap_unchecked()) };
 
     unsafe {
-        MIXTURES.temperature_turf_share(id!(src), &mut turf_sharer, conduction_coefficient);
+        MIXTURES.temperature_turf_share(
+            mixture_id!(src)?,
+            &mut turf_sharer,
+            conduction_coefficient,
+        );
     }
 
     null!()
@@ -421,7 +743,7 @@ pub fn temperature_turf_share(src: ByondValue, mut turf_sharer: ByondValue, cond
 pub fn compare(src: ByondValue, sample: ByondValue) {
     profile_proc!("compare");
 
-    value!(unsafe { MIXTURES.compare(id!(src), id!(sample)) })
+    value!(unsafe { MIXTURES.compare(mixture_id!(src)?, mixture_id!(sample)?) })
 }
 
 #[byondapi::bind]
@@ -429,7 +751,10 @@ pub fn get_breath_partial_pressure(src: ByondValue, gas_pressure: ByondValue) {
     profile_proc!("get_breath_partial_pressure");
 
     value!(unsafe {
-        MIXTURES.get_breath_partial_pressure(id!(src), gas_pressure.get_number().unwrap_unchecked())
+        MIXTURES.get_breath_partial_pressure(
+            mixture_id!(src)?,
+            from_number(gas_pressure.get_number().unwrap_unchecked()),
+        )
     })
 }
 
@@ -439,6 +764,25 @@ pub fn get_true_breath_pressure(src: ByondValue, breath_pp: ByondValue) {
     profile_proc!("get_true_breath_pressure");
 
     value!(unsafe {
-        MIXTURES.get_true_breath_pressure(id!(src), breath_pp.get_number().unwrap_unchecked())
+        MIXTURES.get_true_breath_pressure(
+            mixture_id!(src)?,
+            from_number(breath_pp.get_number().unwrap_unchecked()),
+        )
     })
 }
+
+#[byondapi::bind]
+pub fn breathe(breath: ByondValue, species: ByondValue) {
+    profile_proc!("breathe");
+
+    let species = SpeciesProfile::new(species);
+
+    unsafe { MIXTURES.breathe(mixture_id!(breath)?, &species) }.to_list()
+}
+
+#[byondapi::bind]
+pub fn analyze(src: ByondValue) {
+    profile_proc!("analyze");
+
+    unsafe { MIXTURES.analyze(mixture_id!(src)?) }.to_list()
+}
@@ -0,0 +1,36 @@
+use byondapi::value::types::ValueType;
+use byondapi::value::ByondValue;
+
+use crate::gas_mixture::MIXTURES;
+use crate::{mixture_id, profile_proc, value};
+
+/// Returns the generation of the last change of the air of `src`, compare it with the one from `get_changed_since`.
+#[byondapi::bind]
+pub fn get_generation(src: ByondValue) {
+    profile_proc!("get_generation");
+
+    value!(unsafe { MIXTURES.get_generation(mixture_id!(src)?) } as f32)
+}
+
+/// Pass `0` on the first call and then the returned `generation`.
+/// Returns `list("generation" = generation, "changed" = list(gas_mixture, ...))`.
+/// One subsystem should own the calls: only the latest generation is cheap, and generations are `DM` numbers,
+/// exact only up to `2^24` calls.
+#[byondapi::bind]
+pub fn get_changed_since(generation: ByondValue) {
+    profile_proc!("get_changed_since");
+
+    let (changed, generation) =
+        unsafe { MIXTURES.get_changed_since(generation.get_number()? as u64) };
+
+    let mut mixtures = ByondValue::new_list()?;
+    for id in changed {
+        mixtures.push_list(ByondValue::new_ref(ValueType::Datum, id as u32))?;
+    }
+
+    let mut list = ByondValue::new_list()?;
+    list.write_list_index("generation", generation as f32)?;
+    list.write_list_index("changed", mixtures)?;
+
+    Ok(list)
+}
@@ -0,0 +1,32 @@
+use byondapi::value::ByondValue;
+
+use crate::null;
+
+/// Starts sending spans to `Tracy`. `filter` is an `EnvFilter` directive, e.g. `"debug"` or `"paradise_atmos_rs=trace"`,
+/// `null` is `"debug"`. Can be called only once per process.
+#[cfg(any(feature = "profile", feature = "profile_proc"))]
+#[byondapi::bind]
+pub fn enable_profiling(filter: ByondValue) {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    let filter = if filter.is_null() {
+        String::from("debug")
+    } else {
+        filter.get_string()?
+    };
+
+    tracing::subscriber::set_global_default(
+        tracing_subscriber::registry()
+            .with(tracing_subscriber::EnvFilter::try_new(filter)?)
+            .with(tracing_tracy::TracyLayer::default()),
+    )?;
+
+    null!()
+}
+
+/// The library is built without the `profile` and `profile_proc` features, so there is nothing to enable.
+#[cfg(not(any(feature = "profile", feature = "profile_proc")))]
+#[byondapi::bind]
+pub fn enable_profiling(_filter: ByondValue) {
+    eyre::bail!("Profiling is disabled, build with the `profile` or `profile_proc` feature")
+}
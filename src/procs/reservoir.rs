@@ -0,0 +1,94 @@
+use byondapi::value::ByondValue;
+
+use super::DEFAULT_ATMOS_ADJACENT_TURFS;
+use crate::gas_mixture::MIXTURES;
+use crate::reservoir::{Reservoir, RESERVOIRS};
+use crate::utils::from_number;
+use crate::{mixture_id, profile_proc, value};
+
+#[inline(always)]
+fn get_reservoir(reservoir_id: ByondValue) -> eyre::Result<Reservoir> {
+    let number = reservoir_id.get_number()?;
+    if !number.is_finite() || number < 0.0 || number.fract() != 0.0 {
+        eyre::bail!("Reservoir id must be a non-negative integer, got {number}");
+    }
+    let reservoir_id = number as usize;
+
+    match unsafe { RESERVOIRS.get(reservoir_id) } {
+        Some(reservoir) => Ok(*reservoir),
+        None => eyre::bail!("Reservoir with id {reservoir_id} isn't registered"),
+    }
+}
+
+/// Returns the id of the new reservoir.
+#[byondapi::bind]
+pub fn register_reservoir(name: ByondValue, turf_model: ByondValue) {
+    profile_proc!("register_reservoir");
+
+    let reservoir = Reservoir::new(turf_model)?;
+    let id = unsafe { RESERVOIRS.register(name.get_string()?, reservoir) }?;
+
+    value!(id as f32)
+}
+
+/// Returns the id of the reservoir or `null` if there is no reservoir with such name.
+#[byondapi::bind]
+pub fn get_reservoir_id(name: ByondValue) {
+    profile_proc!("get_reservoir_id");
+
+    match unsafe { RESERVOIRS.get_id(&name.get_string()?) } {
+        Some(id) => value!(id as f32),
+        None => Ok(ByondValue::null()),
+    }
+}
+
+#[byondapi::bind]
+pub fn mimic_reservoir(
+    src: ByondValue,
+    reservoir_id: ByondValue,
+    atmos_adjacent_turfs: ByondValue,
+) {
+    profile_proc!("mimic_reservoir");
+
+    let reservoir = get_reservoir(reservoir_id)?;
+    let atmos_adjacent_turfs = atmos_adjacent_turfs
+        .get_number()
+        .map(from_number)
+        .unwrap_or(DEFAULT_ATMOS_ADJACENT_TURFS);
+
+    value!(unsafe {
+        MIXTURES.mimic(
+            mixture_id!(src)?,
+            reservoir.model,
+            reservoir.thermal_conductivity,
+            reservoir.heat_capacity,
+            atmos_adjacent_turfs,
+        )
+    })
+}
+
+#[byondapi::bind]
+pub fn check_turf_reservoir(
+    src: ByondValue,
+    reservoir_id: ByondValue,
+    atmos_adjacent_turfs: ByondValue,
+) {
+    profile_proc!("check_turf_reservoir");
+
+    let reservoir = get_reservoir(reservoir_id)?;
+    let atmos_adjacent_turfs = atmos_adjacent_turfs
+        .get_number()
+        .map(from_number)
+        .unwrap_or(DEFAULT_ATMOS_ADJACENT_TURFS);
+
+    value!(unsafe { MIXTURES.check_turf(mixture_id!(src)?, reservoir.model, atmos_adjacent_turfs) })
+}
+
+#[byondapi::bind]
+pub fn check_turf_total_reservoir(src: ByondValue, reservoir_id: ByondValue) {
+    profile_proc!("check_turf_total_reservoir");
+
+    let reservoir = get_reservoir(reservoir_id)?;
+
+    value!(unsafe { MIXTURES.check_turf_total(mixture_id!(src)?, reservoir.model) })
+}
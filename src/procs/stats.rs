@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+
+use byondapi::value::ByondValue;
+
+use crate::adjacency::ADJACENCY;
+use crate::stats::{ExportFormat, STATS};
+use crate::{null, profile_proc};
+
+/// Returns the stats of the last finished tick.
+#[byondapi::bind]
+pub fn get_atmos_stats() {
+    profile_proc!("get_atmos_stats");
+
+    unsafe { STATS.last.to_list() }
+}
+
+/// Finishes the current tick of the stats, should be called once per tick of the air subsystem.
+#[byondapi::bind]
+pub fn atmos_stats_tick() {
+    profile_proc!("atmos_stats_tick");
+
+    unsafe { STATS.end_tick(ADJACENCY.turfs_count()) }?;
+
+    null!()
+}
+
+/// `format` is `"json"` or `"prometheus"`, `null` `path` disables the export.
+#[byondapi::bind]
+pub fn set_atmos_stats_export(path: ByondValue, format: ByondValue) {
+    profile_proc!("set_atmos_stats_export");
+
+    if path.is_null() {
+        unsafe { STATS.disable_export() };
+
+        return null!();
+    }
+
+    let format = format.get_string()?;
+    let Some(format) = ExportFormat::from_name(&format) else {
+        eyre::bail!("Unknown stats export format: {format}");
+    };
+
+    unsafe { STATS.set_export(PathBuf::from(path.get_string()?), format) };
+
+    null!()
+}
@@ -0,0 +1,81 @@
+mod agent_b;
+mod hydrogen_fire;
+mod plasma_fire;
+mod sleeping_agent_decomposition;
+
+use once_cell::unsync::Lazy;
+
+use crate::gas_mixture::{Gas, Mixture};
+use crate::utils::Float;
+
+use agent_b::AgentB;
+use hydrogen_fire::HydrogenFire;
+use plasma_fire::PlasmaFire;
+use sleeping_agent_decomposition::SleepingAgentDecomposition;
+
+/// ### Description
+/// Reactions that [`Mixture::react`] runs, in priority order.
+pub static mut REACTIONS: Lazy<Reactions> = Lazy::new(Reactions::default);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReactionResult {
+    NoReaction,
+    /// A notable reaction occured, `DM` uses it to update pipenets.
+    Reacting,
+}
+
+pub trait Reaction {
+    /// ### Description
+    /// The mixture must be hotter than it.
+    fn minimum_temperature(&self) -> Float;
+
+    /// ### Description
+    /// The mixture must have more moles than these of each gas.
+    fn minimum_moles(&self) -> &[(Gas, Float)];
+
+    /// ### Description
+    /// Called only if the mixture meets the requirements.
+    unsafe fn react(&self, mixtures: &mut Mixture, id: usize) -> ReactionResult;
+
+    #[must_use]
+    #[inline(always)]
+    unsafe fn requirements_met(&self, mixtures: &Mixture, id: usize) -> bool {
+        mixtures.get_temperature(id) > self.minimum_temperature()
+            && self
+                .minimum_moles()
+                .iter()
+                .all(|&(gas, moles)| mixtures.get_gas(id, gas) > moles)
+    }
+}
+
+pub struct Reactions {
+    reactions: Vec<Box<dyn Reaction>>,
+}
+
+impl Reactions {
+    /// ### Description
+    /// Adds a reaction after all the registered ones, so it has the lowest priority.
+    #[inline(always)]
+    pub fn register(&mut self, reaction: Box<dyn Reaction>) {
+        self.reactions.push(reaction);
+    }
+
+    #[inline(always)]
+    pub fn iter(&self) -> impl Iterator<Item = &(dyn Reaction + 'static)> {
+        self.reactions.iter().map(Box::as_ref)
+    }
+}
+
+impl Default for Reactions {
+    fn default() -> Self {
+        let mut reactions = Self {
+            reactions: Vec::new(),
+        };
+        reactions.register(Box::new(AgentB));
+        reactions.register(Box::new(PlasmaFire));
+        reactions.register(Box::new(HydrogenFire));
+        reactions.register(Box::new(SleepingAgentDecomposition));
+
+        reactions
+    }
+}
@@ -0,0 +1,58 @@
+use super::{Reaction, ReactionResult};
+use crate::constants::*;
+use crate::gas_mixture::{Gas, Mixture};
+use crate::profile;
+use crate::utils::Float;
+
+/// ### Description
+/// `agent_b` catalyses `carbon_dioxide` into `oxygen` in hot plasma, releasing heat.
+pub struct AgentB;
+
+impl AgentB {
+    const MINIMUM_TEMPERATURE: Float = 900.0;
+    const MINIMUM_MOLES: [(Gas, Float); 3] = [
+        (Gas::AgentB, 0.0),
+        (Gas::Toxins, MINIMUM_HEAT_CAPACITY),
+        (Gas::CarbonDioxide, MINIMUM_HEAT_CAPACITY),
+    ];
+    const ENERGY_RELEASED: Float = 20_000.0;
+}
+
+impl Reaction for AgentB {
+    #[inline(always)]
+    fn minimum_temperature(&self) -> Float {
+        Self::MINIMUM_TEMPERATURE
+    }
+
+    #[inline(always)]
+    fn minimum_moles(&self) -> &[(Gas, Float)] {
+        &Self::MINIMUM_MOLES
+    }
+
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    unsafe fn react(&self, mixtures: &mut Mixture, id: usize) -> ReactionResult {
+        profile!("agent_b");
+
+        let gases = [
+            mixtures.get_carbon_dioxide(id) * 0.75,
+            mixtures.get_toxins(id) * 0.25,
+            mixtures.get_agent_b(id) * 0.05,
+        ];
+
+        let reaction_rate = gases
+            .into_iter()
+            .min_by(|a, b| a.total_cmp(b))
+            .unwrap_unchecked();
+
+        mixtures.sub_carbon_dioxide(id, reaction_rate);
+        mixtures.add_oxygen(id, reaction_rate);
+        mixtures.sub_agent_b(id, reaction_rate * 0.05);
+        mixtures.add_temperature(
+            id,
+            reaction_rate * Self::ENERGY_RELEASED / mixtures.heat_capacity(id),
+        );
+
+        ReactionResult::Reacting
+    }
+}
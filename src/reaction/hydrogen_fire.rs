@@ -0,0 +1,80 @@
+use super::{Reaction, ReactionResult};
+use crate::config::CONFIG;
+use crate::constants::*;
+use crate::gas_mixture::{Gas, Mixture};
+use crate::profile;
+use crate::utils::Float;
+
+/// ### Description
+/// `hydrogen` burning in `oxygen` into `water_vapour`, or into nothing if
+/// [`crate::config::Config::hydrogen_fire_produces_water_vapour`] is disabled.
+/// Scales with the temperature the same way as [`super::plasma_fire::PlasmaFire`], but has its own curve.
+pub struct HydrogenFire;
+
+impl HydrogenFire {
+    const MINIMUM_MOLES: [(Gas, Float); 2] = [
+        (Gas::Hydrogen, MINIMUM_HEAT_CAPACITY),
+        (Gas::Oxygen, MINIMUM_HEAT_CAPACITY),
+    ];
+}
+
+impl Reaction for HydrogenFire {
+    #[inline(always)]
+    fn minimum_temperature(&self) -> Float {
+        unsafe { CONFIG.hydrogen_minimum_burn_temperature }
+    }
+
+    #[inline(always)]
+    fn minimum_moles(&self) -> &[(Gas, Float)] {
+        &Self::MINIMUM_MOLES
+    }
+
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    unsafe fn react(&self, mixtures: &mut Mixture, id: usize) -> ReactionResult {
+        profile!("hydrogen_fire");
+
+        let old_heat_capacity = mixtures.heat_capacity(id);
+        let temperature = mixtures.get_temperature(id);
+
+        let temperature_scale = if temperature > CONFIG.hydrogen_upper_temperature {
+            1.0
+        } else {
+            (temperature - CONFIG.hydrogen_minimum_burn_temperature)
+                / (CONFIG.hydrogen_upper_temperature - CONFIG.hydrogen_minimum_burn_temperature)
+        };
+
+        let hydrogen = mixtures.get_hydrogen(id);
+        let oxygen = mixtures.get_oxygen(id);
+
+        let hydrogen_burn_rate = if oxygen > hydrogen * CONFIG.hydrogen_oxygen_fullburn {
+            (hydrogen * temperature_scale) / CONFIG.hydrogen_burn_rate_delta
+        } else {
+            (temperature_scale * (oxygen / CONFIG.hydrogen_oxygen_fullburn))
+                / CONFIG.hydrogen_burn_rate_delta
+        };
+
+        if hydrogen_burn_rate <= MINIMUM_HEAT_CAPACITY {
+            return ReactionResult::NoReaction;
+        }
+
+        mixtures.sub_hydrogen(id, hydrogen_burn_rate);
+        mixtures.sub_oxygen(id, hydrogen_burn_rate * HYDROGEN_OXYGEN_BURN_RATE);
+        if CONFIG.hydrogen_fire_produces_water_vapour {
+            mixtures.add_water_vapour(id, hydrogen_burn_rate);
+        }
+        mixtures.add_fuel_burnt(id, hydrogen_burn_rate * (HYDROGEN_OXYGEN_BURN_RATE + 1.0));
+
+        let energy_released = CONFIG.fire_hydrogen_energy_released * hydrogen_burn_rate;
+        let new_heat_capacity = mixtures.heat_capacity(id);
+
+        if new_heat_capacity > MINIMUM_HEAT_CAPACITY {
+            mixtures.set_temperature(
+                id,
+                (temperature * old_heat_capacity + energy_released) / new_heat_capacity,
+            );
+        }
+
+        ReactionResult::Reacting
+    }
+}
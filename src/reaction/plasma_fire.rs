@@ -0,0 +1,87 @@
+use super::{Reaction, ReactionResult};
+use crate::config::CONFIG;
+use crate::constants::*;
+use crate::gas_mixture::{Gas, Mixture};
+use crate::profile;
+use crate::utils::Float;
+
+/// ### Description
+/// Plasma burning in oxygen into `carbon_dioxide`, the same as `fire` in `DM`.
+pub struct PlasmaFire;
+
+impl PlasmaFire {
+    const MINIMUM_MOLES: [(Gas, Float); 1] = [(Gas::Toxins, MINIMUM_HEAT_CAPACITY)];
+}
+
+impl Reaction for PlasmaFire {
+    #[inline(always)]
+    fn minimum_temperature(&self) -> Float {
+        unsafe { CONFIG.fire_minimum_temperature_to_exist }
+    }
+
+    #[inline(always)]
+    fn minimum_moles(&self) -> &[(Gas, Float)] {
+        &Self::MINIMUM_MOLES
+    }
+
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    unsafe fn react(&self, mixtures: &mut Mixture, id: usize) -> ReactionResult {
+        profile!("fire");
+
+        let mut energy_released = 0.0;
+        let old_heat_capacity = mixtures.heat_capacity(id);
+
+        let plasma_burn_rate;
+        let oxygen_burn_rate;
+        let temperature = mixtures.get_temperature(id);
+
+        let temperature_scale = if temperature > CONFIG.plasma_upper_temperature {
+            1.0
+        } else {
+            (temperature - CONFIG.plasma_minimum_burn_temperature)
+                / (CONFIG.plasma_upper_temperature - CONFIG.plasma_minimum_burn_temperature)
+        };
+
+        if temperature_scale > Default::default() {
+            oxygen_burn_rate = CONFIG.oxygen_burn_rate_base - temperature_scale;
+
+            let toxins = mixtures.get_toxins(id);
+            let oxygen = mixtures.get_oxygen(id);
+
+            if oxygen > toxins * CONFIG.plasma_oxygen_fullburn {
+                plasma_burn_rate = (toxins * temperature_scale) / CONFIG.plasma_burn_rate_delta;
+            } else {
+                plasma_burn_rate = (temperature_scale * (oxygen / CONFIG.plasma_oxygen_fullburn))
+                    / CONFIG.plasma_burn_rate_delta;
+            }
+
+            if plasma_burn_rate > MINIMUM_HEAT_CAPACITY {
+                mixtures.sub_toxins(id, plasma_burn_rate);
+                mixtures.sub_oxygen(id, plasma_burn_rate * oxygen_burn_rate);
+                mixtures.add_carbon_dioxide(id, plasma_burn_rate);
+                mixtures.add_fuel_burnt(id, plasma_burn_rate * (oxygen_burn_rate + 1.0));
+
+                energy_released += CONFIG.fire_plasma_energy_released * plasma_burn_rate;
+            }
+        }
+
+        if energy_released > Default::default() {
+            let new_heat_capacity = mixtures.heat_capacity(id);
+
+            if new_heat_capacity > MINIMUM_HEAT_CAPACITY {
+                mixtures.set_temperature(
+                    id,
+                    (mixtures.get_temperature(id) * old_heat_capacity + energy_released)
+                        / new_heat_capacity,
+                );
+            }
+        }
+
+        if mixtures.get_fuel_burnt(id) > 0.0 {
+            ReactionResult::Reacting
+        } else {
+            ReactionResult::NoReaction
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use super::{Reaction, ReactionResult};
+use crate::config::CONFIG;
+use crate::constants::*;
+use crate::gas_mixture::{Gas, Mixture};
+use crate::profile;
+use crate::utils::Float;
+
+/// ### Description
+/// Hot `sleeping_agent` decomposes into `nitrogen` and `oxygen` (2 N2O -> 2 N2 + O2), releasing heat.
+/// Makes `sleeping_agent` a dangerous oxidiser for plasma fires.
+pub struct SleepingAgentDecomposition;
+
+impl SleepingAgentDecomposition {
+    const MINIMUM_MOLES: [(Gas, Float); 1] = [(Gas::SleepingAgent, MINIMUM_HEAT_CAPACITY)];
+}
+
+impl Reaction for SleepingAgentDecomposition {
+    #[inline(always)]
+    fn minimum_temperature(&self) -> Float {
+        unsafe { CONFIG.sleeping_agent_decomposition_minimum_temperature }
+    }
+
+    #[inline(always)]
+    fn minimum_moles(&self) -> &[(Gas, Float)] {
+        &Self::MINIMUM_MOLES
+    }
+
+    #[cfg_attr(feature = "profile", inline(never))]
+    #[cfg_attr(not(feature = "profile"), inline(always))]
+    unsafe fn react(&self, mixtures: &mut Mixture, id: usize) -> ReactionResult {
+        profile!("sleeping_agent_decomposition");
+
+        let old_heat_capacity = mixtures.heat_capacity(id);
+        let temperature = mixtures.get_temperature(id);
+
+        let temperature_scale =
+            if temperature > CONFIG.sleeping_agent_decomposition_upper_temperature {
+                1.0
+            } else {
+                (temperature - CONFIG.sleeping_agent_decomposition_minimum_temperature)
+                    / (CONFIG.sleeping_agent_decomposition_upper_temperature
+                        - CONFIG.sleeping_agent_decomposition_minimum_temperature)
+            };
+
+        let decomposition_rate = mixtures.get_sleeping_agent(id) * temperature_scale
+            / CONFIG.sleeping_agent_decomposition_rate_delta;
+        if decomposition_rate <= MINIMUM_HEAT_CAPACITY {
+            return ReactionResult::NoReaction;
+        }
+
+        mixtures.sub_sleeping_agent(id, decomposition_rate);
+        mixtures.add_nitrogen(id, decomposition_rate);
+        mixtures.add_oxygen(id, decomposition_rate * 0.5);
+
+        let energy_released =
+            CONFIG.sleeping_agent_decomposition_energy_released * decomposition_rate;
+        let new_heat_capacity = mixtures.heat_capacity(id);
+
+        if new_heat_capacity > MINIMUM_HEAT_CAPACITY {
+            mixtures.set_temperature(
+                id,
+                (temperature * old_heat_capacity + energy_released) / new_heat_capacity,
+            );
+        }
+
+        ReactionResult::Reacting
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use byondapi::value::ByondValue;
+use once_cell::unsync::Lazy;
+
+use crate::constants::*;
+use crate::turf::Turf;
+use crate::utils::{from_number, Float};
+
+/// ### Description
+/// Immutable model turfs with infinite capacity (planetary atmospheres, lavaland and space), registered once per round.
+pub static mut RESERVOIRS: Lazy<Reservoirs> = Lazy::new(Reservoirs::new);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Reservoir {
+    pub model: Turf,
+    pub thermal_conductivity: Float,
+    pub heat_capacity: Float,
+}
+
+impl Reservoir {
+    const THERMAL_CONDUCTIVITY: &'static str = "thermal_conductivity";
+    const HEAT_CAPACITY: &'static str = "heat_capacity";
+
+    /// ### Description
+    /// Reads the gases, the temperature and the thermal properties of a turf datum.
+    pub fn new(turf: ByondValue) -> eyre::Result<Self> {
+        Ok(Self {
+            model: Turf::try_new(turf)?,
+            thermal_conductivity: from_number(turf.read_number(Self::THERMAL_CONDUCTIVITY)?),
+            heat_capacity: from_number(turf.read_number(Self::HEAT_CAPACITY)?),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Reservoirs {
+    reservoirs: Vec<Reservoir>,
+    ids: HashMap<String, usize>,
+}
+
+impl Reservoirs {
+    pub const SPACE_ID: usize = 0;
+    const SPACE_NAME: &'static str = "space";
+
+    /// ### Description
+    /// Creates the registry with space already registered as [`Reservoirs::SPACE_ID`].
+    #[must_use]
+    fn new() -> Self {
+        Self {
+            reservoirs: vec![Reservoir {
+                model: Turf::SPACE,
+                thermal_conductivity: OPEN_HEAT_TRANSFER_COEFFICIENT,
+                heat_capacity: HEAT_CAPACITY_VACUUM,
+            }],
+            ids: HashMap::from([(Self::SPACE_NAME.to_owned(), Self::SPACE_ID)]),
+        }
+    }
+
+    /// ### Description
+    /// Registers a reservoir under a unique `name` and returns its id.
+    pub fn register(&mut self, name: String, reservoir: Reservoir) -> eyre::Result<usize> {
+        if self.ids.contains_key(&name) {
+            eyre::bail!("Reservoir `{name}` is already registered");
+        }
+
+        let id = self.reservoirs.len();
+        self.reservoirs.push(reservoir);
+        self.ids.insert(name, id);
+
+        Ok(id)
+    }
+
+    #[must_use]
+    #[inline(always)]
+    pub fn get(&self, id: usize) -> Option<&Reservoir> {
+        self.reservoirs.get(id)
+    }
+
+    #[must_use]
+    #[inline(always)]
+    pub fn get_id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+}
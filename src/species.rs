@@ -0,0 +1,87 @@
+use byondapi::value::ByondValue;
+
+use crate::constants::*;
+use crate::gas_mixture::Gas;
+use crate::utils::{from_number, Float};
+
+/// ### Description
+/// Breathing limits of a species, read from a `/datum/species`.
+/// All limits are partial pressures in kPa, `0.0` disables the check.
+pub struct SpeciesProfile {
+    pub breath_type: Gas,
+    pub exhale_type: Gas,
+    pub safe_breath_min: Float,
+    pub safe_breath_max: Float,
+    pub safe_co2_max: Float,
+    pub safe_toxins_max: Float,
+    pub safe_agent_b_max: Float,
+    pub sleeping_agent_para_min: Float,
+    pub sleeping_agent_sleep_min: Float,
+}
+
+impl SpeciesProfile {
+    const BREATH_TYPE: &'static str = "breath_type";
+    const EXHALE_TYPE: &'static str = "exhale_type";
+    const SAFE_BREATH_MIN: &'static str = "safe_oxygen_min";
+    const SAFE_BREATH_MAX: &'static str = "safe_oxygen_max";
+    const SAFE_CO2_MAX: &'static str = "safe_co2_max";
+    const SAFE_TOXINS_MAX: &'static str = "safe_toxins_max";
+    const SAFE_AGENT_B_MAX: &'static str = "safe_agent_b_max";
+    const SLEEPING_AGENT_PARA_MIN: &'static str = "SA_para_min";
+    const SLEEPING_AGENT_SLEEP_MIN: &'static str = "SA_sleep_min";
+
+    /// ### Description
+    /// Reads the profile from a species datum.
+    /// Vars that are missing or aren't of the expected type fall back to the human defaults,
+    /// so a species only needs to override what differs.
+    #[must_use]
+    pub fn new(species: ByondValue) -> Self {
+        let read_gas = |name: &str, default: Gas| {
+            species
+                .read_string(name)
+                .ok()
+                .and_then(|gas| Gas::from_name(&gas))
+                .unwrap_or(default)
+        };
+        let read_number = |name: &str, default: Float| {
+            species
+                .read_number(name)
+                .map(from_number)
+                .unwrap_or(default)
+        };
+
+        Self {
+            breath_type: read_gas(Self::BREATH_TYPE, Gas::Oxygen),
+            exhale_type: read_gas(Self::EXHALE_TYPE, Gas::CarbonDioxide),
+            safe_breath_min: read_number(Self::SAFE_BREATH_MIN, DEFAULT_SAFE_OXYGEN_MIN),
+            safe_breath_max: read_number(Self::SAFE_BREATH_MAX, DEFAULT_SAFE_OXYGEN_MAX),
+            safe_co2_max: read_number(Self::SAFE_CO2_MAX, DEFAULT_SAFE_CO2_MAX),
+            safe_toxins_max: read_number(Self::SAFE_TOXINS_MAX, DEFAULT_SAFE_TOXINS_MAX),
+            safe_agent_b_max: read_number(Self::SAFE_AGENT_B_MAX, DEFAULT_SAFE_AGENT_B_MAX),
+            sleeping_agent_para_min: read_number(
+                Self::SLEEPING_AGENT_PARA_MIN,
+                DEFAULT_SLEEPING_AGENT_PARA_MIN,
+            ),
+            sleeping_agent_sleep_min: read_number(
+                Self::SLEEPING_AGENT_SLEEP_MIN,
+                DEFAULT_SLEEPING_AGENT_SLEEP_MIN,
+            ),
+        }
+    }
+}
+
+impl Default for SpeciesProfile {
+    fn default() -> Self {
+        Self {
+            breath_type: Gas::Oxygen,
+            exhale_type: Gas::CarbonDioxide,
+            safe_breath_min: DEFAULT_SAFE_OXYGEN_MIN,
+            safe_breath_max: DEFAULT_SAFE_OXYGEN_MAX,
+            safe_co2_max: DEFAULT_SAFE_CO2_MAX,
+            safe_toxins_max: DEFAULT_SAFE_TOXINS_MAX,
+            safe_agent_b_max: DEFAULT_SAFE_AGENT_B_MAX,
+            sleeping_agent_para_min: DEFAULT_SLEEPING_AGENT_PARA_MIN,
+            sleeping_agent_sleep_min: DEFAULT_SLEEPING_AGENT_SLEEP_MIN,
+        }
+    }
+}
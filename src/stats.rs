@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs::OpenOptions;
+use std::io::Write as _;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use byondapi::value::ByondValue;
+use once_cell::unsync::Lazy;
+use serde::Serialize;
+
+use crate::utils::{to_number, Float};
+
+/// ### Description
+/// Counters of what the atmos core did, kept per tick.
+pub static mut STATS: Lazy<Stats> = Lazy::new(Stats::default);
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct TickStats {
+    pub tick: u64,
+    pub registered_mixtures: usize,
+    pub share_calls: u64,
+    /// Sum of `last_share` of every share and mimic.
+    pub moles_moved: Float,
+    pub reactions: u64,
+    pub fuel_burnt: Float,
+    pub active_turfs: usize,
+    pub suspended_turfs: usize,
+    /// Slightly negative, `NaN` or infinite moles and `NaN` or infinite temperatures fixed by the sanitization.
+    pub sanitized_values: u64,
+    /// Moles below `-SANITIZATION_EPSILON` found by the sanitization, too big for a float error.
+    pub large_negative_moles: u64,
+    /// Microseconds spent in each proc.
+    pub proc_time: HashMap<&'static str, f64>,
+}
+
+impl TickStats {
+    const TICK: &'static str = "tick";
+    const REGISTERED_MIXTURES: &'static str = "registered_mixtures";
+    const SHARE_CALLS: &'static str = "share_calls";
+    const MOLES_MOVED: &'static str = "moles_moved";
+    const REACTIONS: &'static str = "reactions";
+    const FUEL_BURNT: &'static str = "fuel_burnt";
+    const ACTIVE_TURFS: &'static str = "active_turfs";
+    const SUSPENDED_TURFS: &'static str = "suspended_turfs";
+    const SANITIZED_VALUES: &'static str = "sanitized_values";
+    const LARGE_NEGATIVE_MOLES: &'static str = "large_negative_moles";
+    const PROC_TIME: &'static str = "proc_time";
+
+    /// ### Description
+    /// Converts the stats to an associative `DM` list, `proc_time` is a nested list keyed by the name of the proc.
+    pub fn to_list(&self) -> eyre::Result<ByondValue> {
+        let mut proc_time = ByondValue::new_list()?;
+        for (name, time) in &self.proc_time {
+            proc_time.write_list_index(*name, *time as f32)?;
+        }
+
+        let mut list = ByondValue::new_list()?;
+        list.write_list_index(Self::TICK, self.tick as f32)?;
+        list.write_list_index(Self::REGISTERED_MIXTURES, self.registered_mixtures as f32)?;
+        list.write_list_index(Self::SHARE_CALLS, self.share_calls as f32)?;
+        list.write_list_index(Self::MOLES_MOVED, to_number(self.moles_moved))?;
+        list.write_list_index(Self::REACTIONS, self.reactions as f32)?;
+        list.write_list_index(Self::FUEL_BURNT, to_number(self.fuel_burnt))?;
+        list.write_list_index(Self::ACTIVE_TURFS, self.active_turfs as f32)?;
+        list.write_list_index(Self::SUSPENDED_TURFS, self.suspended_turfs as f32)?;
+        list.write_list_index(Self::SANITIZED_VALUES, self.sanitized_values as f32)?;
+        list.write_list_index(Self::LARGE_NEGATIVE_MOLES, self.large_negative_moles as f32)?;
+        list.write_list_index(Self::PROC_TIME, proc_time)?;
+
+        Ok(list)
+    }
+
+    #[must_use]
+    fn to_prometheus(&self) -> String {
+        const PREFIX: &str = "paradise_atmos";
+
+        let mut text = String::new();
+        for (name, value) in [
+            (Self::TICK, self.tick as f64),
+            (Self::REGISTERED_MIXTURES, self.registered_mixtures as f64),
+            (Self::SHARE_CALLS, self.share_calls as f64),
+            (Self::MOLES_MOVED, f64::from(to_number(self.moles_moved))),
+            (Self::REACTIONS, self.reactions as f64),
+            (Self::FUEL_BURNT, f64::from(to_number(self.fuel_burnt))),
+            (Self::ACTIVE_TURFS, self.active_turfs as f64),
+            (Self::SUSPENDED_TURFS, self.suspended_turfs as f64),
+            (Self::SANITIZED_VALUES, self.sanitized_values as f64),
+            (Self::LARGE_NEGATIVE_MOLES, self.large_negative_moles as f64),
+        ] {
+            let _ = writeln!(text, "{PREFIX}_{name} {value}");
+        }
+
+        for (name, time) in &self.proc_time {
+            let _ = writeln!(
+                text,
+                "{PREFIX}_{}_microseconds{{proc=\"{name}\"}} {time}",
+                Self::PROC_TIME
+            );
+        }
+
+        text
+    }
+
+    /// ### Description
+    /// Sends the counters of the tick to the plots of `Tracy`, does nothing if `Tracy` isn't running.
+    #[cfg(any(feature = "profile", feature = "profile_proc"))]
+    fn plot(&self) {
+        use tracing_tracy::client::{plot_name, Client};
+
+        let Some(client) = Client::running() else {
+            return;
+        };
+        macro_rules! plot {
+            ($name:literal, $value:expr) => {
+                client.plot(plot_name!($name), $value)
+            };
+        }
+
+        plot!("active_turfs", self.active_turfs as f64);
+        plot!("suspended_turfs", self.suspended_turfs as f64);
+        plot!("registered_mixtures", self.registered_mixtures as f64);
+        plot!("share_calls", self.share_calls as f64);
+        plot!("moles_moved", f64::from(to_number(self.moles_moved)));
+        plot!("reactions", self.reactions as f64);
+        plot!("fuel_burnt", f64::from(to_number(self.fuel_burnt)));
+        plot!("sanitized_values", self.sanitized_values as f64);
+        plot!("large_negative_moles", self.large_negative_moles as f64);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ExportFormat {
+    /// A line of `JSON` is appended to the file each tick.
+    JsonLines,
+    /// The file is overwritten each tick, as the textfile collector of `Prometheus` expects.
+    Prometheus,
+}
+
+impl ExportFormat {
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "json" => Some(Self::JsonLines),
+            "prometheus" => Some(Self::Prometheus),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Export {
+    path: PathBuf,
+    format: ExportFormat,
+}
+
+#[derive(Debug, Default)]
+pub struct Stats {
+    pub registered_mixtures: usize,
+    /// Stats of the tick that is in progress.
+    pub current: TickStats,
+    /// Stats of the last finished tick.
+    pub last: TickStats,
+    export: Option<Export>,
+}
+
+impl Stats {
+    #[inline(always)]
+    pub fn set_export(&mut self, path: PathBuf, format: ExportFormat) {
+        self.export = Some(Export { path, format });
+    }
+
+    #[inline(always)]
+    pub fn disable_export(&mut self) {
+        self.export = None;
+    }
+
+    #[inline(always)]
+    pub fn record_proc_time(&mut self, name: &'static str, microseconds: f64) {
+        *self.current.proc_time.entry(name).or_default() += microseconds;
+    }
+
+    /// ### Description
+    /// Finishes the current tick, exports it if an export is set and starts the next one.
+    /// `simulated_turfs` is the number of turfs known to the turf tick, the ones that weren't active are suspended.
+    pub fn end_tick(&mut self, simulated_turfs: usize) -> eyre::Result<()> {
+        let next_tick = self.current.tick + 1;
+
+        self.current.registered_mixtures = self.registered_mixtures;
+        self.current.suspended_turfs = simulated_turfs.saturating_sub(self.current.active_turfs);
+        #[cfg(any(feature = "profile", feature = "profile_proc"))]
+        self.current.plot();
+        self.last = std::mem::take(&mut self.current);
+        self.current.tick = next_tick;
+
+        let Some(export) = &self.export else {
+            return Ok(());
+        };
+
+        match export.format {
+            ExportFormat::JsonLines => {
+                let mut file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&export.path)?;
+                serde_json::to_writer(&mut file, &self.last)?;
+                writeln!(file)?;
+            }
+            ExportFormat::Prometheus => std::fs::write(&export.path, self.last.to_prometheus())?,
+        }
+
+        Ok(())
+    }
+}
+
+/// ### Description
+/// Adds the time from its creation to its drop to the time of the proc in [`STATS`].
+/// Created only with [`crate::config::Config::proc_timing`], see [`ProcTimer::start`].
+pub struct ProcTimer {
+    name: &'static str,
+    start: Instant,
+}
+
+impl ProcTimer {
+    #[must_use]
+    #[inline(always)]
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            start: Instant::now(),
+        }
+    }
+
+    /// ### Description
+    /// Starts a timer if proc timing is enabled in [`crate::config::CONFIG`].
+    #[must_use]
+    #[inline(always)]
+    pub fn start(name: &'static str) -> Option<Self> {
+        unsafe { crate::config::CONFIG.proc_timing }.then(|| Self::new(name))
+    }
+}
+
+impl Drop for ProcTimer {
+    #[inline(always)]
+    fn drop(&mut self) {
+        let microseconds = self.start.elapsed().as_secs_f64() * 1_000_000.0;
+
+        unsafe { STATS.record_proc_time(self.name, microseconds) };
+    }
+}
@@ -1,13 +1,20 @@
 use byondapi::value::ByondValue;
 
+use crate::constants::TCMB;
+use crate::gas_mixture::Gas;
+use crate::utils::{from_number, Float};
+
+#[derive(Debug, Clone, Copy)]
 pub struct Turf {
-    pub oxygen: f32,
-    pub carbon_dioxide: f32,
-    pub nitrogen: f32,
-    pub toxins: f32,
-    pub sleeping_agent: f32,
-    pub agent_b: f32,
-    pub temperature: f32,
+    pub oxygen: Float,
+    pub carbon_dioxide: Float,
+    pub nitrogen: Float,
+    pub toxins: Float,
+    pub hydrogen: Float,
+    pub sleeping_agent: Float,
+    pub agent_b: Float,
+    pub water_vapour: Float,
+    pub temperature: Float,
 }
 
 impl Turf {
@@ -15,49 +22,124 @@ impl Turf {
     const CARBON_DIOXIDE: &'static str = "carbon_dioxide";
     const NITROGEN: &'static str = "nitrogen";
     const TOXINS: &'static str = "toxins";
+    const HYDROGEN: &'static str = "hydrogen";
     const SLEEPING_AGENT: &'static str = "sleeping_agent";
     const AGENT_B: &'static str = "agent_b";
+    const WATER_VAPOUR: &'static str = "water_vapour";
     const TEMPERATURE: &'static str = "temperature";
 
+    /// ### Description
+    /// Model of space: no gas at all and the temperature of the cosmic microwave background.
+    pub const SPACE: Self = Self {
+        oxygen: 0.0,
+        carbon_dioxide: 0.0,
+        nitrogen: 0.0,
+        toxins: 0.0,
+        hydrogen: 0.0,
+        sleeping_agent: 0.0,
+        agent_b: 0.0,
+        water_vapour: 0.0,
+        temperature: TCMB,
+    };
+
     #[inline(always)]
     #[must_use]
     pub unsafe fn new(turf: ByondValue) -> Self {
         Self {
-            oxygen: turf
-                .read_var(Self::OXYGEN)
-                .unwrap_unchecked()
-                .get_number()
-                .unwrap_unchecked(),
-            carbon_dioxide: turf
-                .read_var(Self::CARBON_DIOXIDE)
-                .unwrap_unchecked()
-                .get_number()
-                .unwrap_unchecked(),
-            nitrogen: turf
-                .read_var(Self::NITROGEN)
-                .unwrap_unchecked()
-                .get_number()
-                .unwrap_unchecked(),
-            toxins: turf
-                .read_var(Self::TOXINS)
-                .unwrap_unchecked()
-                .get_number()
-                .unwrap_unchecked(),
-            sleeping_agent: turf
-                .read_var(Self::SLEEPING_AGENT)
-                .unwrap_unchecked()
-                .get_number()
-                .unwrap_unchecked(),
-            agent_b: turf
-                .read_var(Self::AGENT_B)
-                .unwrap_unchecked()
-                .get_number()
-                .unwrap_unchecked(),
-            temperature: turf
-                .read_var(Self::TEMPERATURE)
-                .unwrap_unchecked()
-                .get_number()
-                .unwrap_unchecked(),
+            oxygen: from_number(
+                turf.read_var(Self::OXYGEN)
+                    .unwrap_unchecked()
+                    .get_number()
+                    .unwrap_unchecked(),
+            ),
+            carbon_dioxide: from_number(
+                turf.read_var(Self::CARBON_DIOXIDE)
+                    .unwrap_unchecked()
+                    .get_number()
+                    .unwrap_unchecked(),
+            ),
+            nitrogen: from_number(
+                turf.read_var(Self::NITROGEN)
+                    .unwrap_unchecked()
+                    .get_number()
+                    .unwrap_unchecked(),
+            ),
+            toxins: from_number(
+                turf.read_var(Self::TOXINS)
+                    .unwrap_unchecked()
+                    .get_number()
+                    .unwrap_unchecked(),
+            ),
+            hydrogen: turf
+                .read_number(Self::HYDROGEN)
+                .map(from_number)
+                .unwrap_or_default(),
+            sleeping_agent: from_number(
+                turf.read_var(Self::SLEEPING_AGENT)
+                    .unwrap_unchecked()
+                    .get_number()
+                    .unwrap_unchecked(),
+            ),
+            agent_b: from_number(
+                turf.read_var(Self::AGENT_B)
+                    .unwrap_unchecked()
+                    .get_number()
+                    .unwrap_unchecked(),
+            ),
+            water_vapour: turf
+                .read_number(Self::WATER_VAPOUR)
+                .map(from_number)
+                .unwrap_or_default(),
+            temperature: from_number(
+                turf.read_var(Self::TEMPERATURE)
+                    .unwrap_unchecked()
+                    .get_number()
+                    .unwrap_unchecked(),
+            ),
         }
     }
+
+    /// ### Description
+    /// Checked [`Turf::new`] for datums that aren't guaranteed to have every var, e.g. registered reservoirs.
+    /// `hydrogen` and `water_vapour` are optional, the same as in [`Turf::new`].
+    pub fn try_new(turf: ByondValue) -> eyre::Result<Self> {
+        Ok(Self {
+            oxygen: from_number(turf.read_number(Self::OXYGEN)?),
+            carbon_dioxide: from_number(turf.read_number(Self::CARBON_DIOXIDE)?),
+            nitrogen: from_number(turf.read_number(Self::NITROGEN)?),
+            toxins: from_number(turf.read_number(Self::TOXINS)?),
+            hydrogen: turf
+                .read_number(Self::HYDROGEN)
+                .map(from_number)
+                .unwrap_or_default(),
+            sleeping_agent: from_number(turf.read_number(Self::SLEEPING_AGENT)?),
+            agent_b: from_number(turf.read_number(Self::AGENT_B)?),
+            water_vapour: turf
+                .read_number(Self::WATER_VAPOUR)
+                .map(from_number)
+                .unwrap_or_default(),
+            temperature: from_number(turf.read_number(Self::TEMPERATURE)?),
+        })
+    }
+
+    #[must_use]
+    #[inline(always)]
+    pub const fn get_gas(&self, gas: Gas) -> Float {
+        match gas {
+            Gas::Oxygen => self.oxygen,
+            Gas::CarbonDioxide => self.carbon_dioxide,
+            Gas::Nitrogen => self.nitrogen,
+            Gas::Toxins => self.toxins,
+            Gas::Hydrogen => self.hydrogen,
+            Gas::SleepingAgent => self.sleeping_agent,
+            Gas::AgentB => self.agent_b,
+            Gas::WaterVapour => self.water_vapour,
+        }
+    }
+
+    #[must_use]
+    #[inline(always)]
+    pub fn total_moles(&self) -> Float {
+        Gas::ALL.into_iter().map(|gas| self.get_gas(gas)).sum()
+    }
 }
@@ -1,5 +1,16 @@
+use byondapi::value::ByondValue;
+
 use crate::constants::*;
 
+/// #### Description
+/// Type of the columns of [`crate::gas_mixture::Mixture`], of the constants and of all the math over them.
+/// `f64` with the `f64` feature, for big volumes like pipenets and planetary air, where `f32` loses moles.
+/// `DM` numbers are always `f32`, so values are converted with [`from_number`] and [`to_number`].
+#[cfg(not(feature = "f64"))]
+pub type Float = f32;
+#[cfg(feature = "f64")]
+pub type Float = f64;
+
 /// #### Description
 /// Creates a `DM`'s `null` via [`auxtools::Value`] wrapped with [`Result::Ok`].
 #[macro_export]
@@ -14,7 +25,7 @@ macro_rules! null {
 #[macro_export]
 macro_rules! value {
     ($value:expr) => {
-        Ok(ByondValue::from($value))
+        Ok($crate::utils::ToValue::to_value($value))
     };
 }
 
@@ -28,21 +39,39 @@ macro_rules! id {
     };
 }
 
+/// #### Description
+/// Gets `id` of a `gas_mixture` from value, checking that its page is allocated, see [`crate::gas_mixture::Mixture::is_allocated`].
+/// Returns `eyre::Result<usize>`, accessing a slot of an unallocated page is UB.
+#[macro_export]
+macro_rules! mixture_id {
+    ($value:expr) => {{
+        let id = $crate::id!($value);
+        if unsafe { $crate::gas_mixture::MIXTURES.is_allocated(id) } {
+            Ok(id)
+        } else {
+            Err(::eyre::eyre!("gas_mixture {id} was never registered"))
+        }
+    }};
+}
+
 #[macro_export]
 macro_rules! profile {
     ($s:expr) => {
         #[cfg(feature = "profile")]
-        let span = span!(tracing::Level::INFO, $s);
+        let span = ::tracing::span!(::tracing::Level::INFO, $s);
         #[cfg(feature = "profile")]
         let _enter = span.enter();
     };
 }
 
+/// #### Description
+/// Profiles a whole proc with `Tracy` and, with [`crate::config::Config::proc_timing`], adds its execution time to [`crate::stats::STATS`].
 #[macro_export]
 macro_rules! profile_proc {
     ($s:expr) => {
+        let _proc_timer = $crate::stats::ProcTimer::start($s);
         #[cfg(feature = "profile_proc")]
-        let span = span!(tracing::Level::INFO, $s);
+        let span = ::tracing::span!(::tracing::Level::INFO, $s);
         #[cfg(feature = "profile_proc")]
         let _enter = span.enter();
     };
@@ -51,25 +80,76 @@ macro_rules! profile_proc {
 // FIXME: Can be possible reason of unexpected behaviour. Check if result is expected.
 #[must_use]
 #[inline(always)]
-pub fn quantize(value: f32) -> f32 {
-    const QUANTIZE_NEAREST: f32 = 0.0001;
+pub fn quantize(value: Float) -> Float {
+    const QUANTIZE_NEAREST: Float = 0.0001;
 
     ((value + (QUANTIZE_NEAREST / 2.0)).div_euclid(QUANTIZE_NEAREST)) * QUANTIZE_NEAREST
 }
 
 #[must_use]
 #[inline(always)]
+#[allow(clippy::too_many_arguments)]
 pub fn calculate_heat_capacity(
-    oxygen: f32,
-    carbon_dioxide: f32,
-    nitrogen: f32,
-    toxins: f32,
-    sleeping_agent: f32,
-    agent_b: f32,
-) -> f32 {
+    oxygen: Float,
+    carbon_dioxide: Float,
+    nitrogen: Float,
+    toxins: Float,
+    hydrogen: Float,
+    sleeping_agent: Float,
+    agent_b: Float,
+    water_vapour: Float,
+) -> Float {
     carbon_dioxide * SPECIFIC_HEAT_CDO
         + (oxygen + nitrogen) * SPECIFIC_HEAT_AIR
         + toxins * SPECIFIC_HEAT_TOXIN
+        + hydrogen * SPECIFIC_HEAT_HYDROGEN
         + sleeping_agent * SPECIFIC_HEAT_N2O
         + agent_b * SPECIFIC_HEAT_AGENT_B
+        + water_vapour * SPECIFIC_HEAT_WATER_VAPOUR
+}
+
+/// #### Description
+/// Converts a `DM` number to [`Float`].
+#[must_use]
+#[inline(always)]
+#[allow(clippy::useless_conversion)]
+pub fn from_number(value: f32) -> Float {
+    value.into()
+}
+
+/// #### Description
+/// Converts a [`Float`] to a `DM` number.
+#[must_use]
+#[inline(always)]
+#[allow(clippy::unnecessary_cast)]
+pub fn to_number(value: Float) -> f32 {
+    value as f32
+}
+
+/// #### Description
+/// Conversion used by [`value!`], so it takes [`Float`] in both precisions.
+pub trait ToValue {
+    fn to_value(self) -> ByondValue;
+}
+
+impl ToValue for bool {
+    #[inline(always)]
+    fn to_value(self) -> ByondValue {
+        ByondValue::from(self)
+    }
+}
+
+impl ToValue for f32 {
+    #[inline(always)]
+    fn to_value(self) -> ByondValue {
+        ByondValue::from(self)
+    }
+}
+
+#[cfg(feature = "f64")]
+impl ToValue for f64 {
+    #[inline(always)]
+    fn to_value(self) -> ByondValue {
+        ByondValue::from(to_number(self))
+    }
 }